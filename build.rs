@@ -0,0 +1,65 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fs, path::Path};
+
+/// Two-digit day files (`NN.rs`) or four-digit year folders (`YYYY`), parsed as numbers.
+fn numbered_entries(dir: &Path, digits: usize, extension: Option<&str>) -> Vec<u32> {
+    let mut entries: Vec<u32> = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = match extension {
+                Some(extension) if path.extension()? == extension => path.file_stem()?,
+                Some(_) => return None,
+                None if path.is_dir() => path.file_name()?,
+                None => return None,
+            };
+            let stem = stem.to_str()?;
+            if stem.len() != digits || !stem.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            stem.parse().ok()
+        })
+        .collect();
+    entries.sort_unstable();
+    entries
+}
+
+/// Generates the solution registry included by `src/days.rs` from the solutions in `src/YYYY/NN.rs`.
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let src_dir = Path::new(&manifest_dir).join("src");
+
+    let mut modules = String::new();
+    let mut registry = String::from("\npub const DAYS: &[Day] = &[\n");
+
+    for year in numbered_entries(&src_dir, 4, None) {
+        let year_dir = src_dir.join(year.to_string());
+        modules.push_str(&format!("pub mod y{} {{\n", year));
+
+        for day in numbered_entries(&year_dir, 2, Some("rs")) {
+            // The solution is included below a glob import of `days::defaults`, so the constants it
+            // declares itself shadow the defaults and the compiler picks the right one.
+            let module_path = year_dir.join(format!("{:02}.rs", day));
+            modules.push_str(&format!(
+                "    #[allow(dead_code)]\n    pub mod day{:02} {{\n        #[allow(unused_imports)]\n        pub use crate::days::defaults::*;\n        include!({:?});\n    }}\n",
+                day, module_path
+            ));
+
+            registry.push_str(&format!(
+                "    day!({}, {}, y{}, day{:02}),\n",
+                year, day, year, day
+            ));
+        }
+
+        modules.push_str("}\n");
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("days.rs"), modules + &registry).unwrap();
+}
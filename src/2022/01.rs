@@ -1,6 +1,5 @@
 fn parse_input(input: &str) -> Vec<u32> {
     let elf_inventories: Vec<&str> = input.split("\n\n").collect();
-    dbg!(&elf_inventories.len());
     elf_inventories
         .into_iter()
         .map(|inv_str| {
@@ -25,25 +24,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(top_3_sum)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_one(&input), Some(24000));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_two(&input), Some(45000));
     }
 }
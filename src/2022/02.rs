@@ -1,7 +1,5 @@
-#[macro_use]
-extern crate lazy_static;
-
 use itertools::Itertools;
+use lazy_static::lazy_static;
 use std::collections::{HashMap, HashSet};
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
@@ -69,11 +67,10 @@ impl Match {
     }
 
     pub fn state(&self) -> Option<State> {
-        if self.my_move.is_none() {
-            None
-        } else if self.my_move.unwrap() == self.their_move {
+        let my_move = self.my_move?;
+        if my_move == self.their_move {
             Some(Draw)
-        } else if WINS.contains(&(self.their_move, self.my_move.unwrap())) {
+        } else if WINS.contains(&(self.their_move, my_move)) {
             Some(Win)
         } else {
             Some(Loss)
@@ -146,23 +143,11 @@ fn calculate_match_score((a, b): (&Move, &Move)) -> u32 {
     let move_score = MOVE_SCORES.get(b).unwrap();
     let result = match_state((a, b));
     let state_score = STATE_SCORES.get(&result).unwrap();
-    let total_score = *move_score + *state_score;
-    dbg!(
-        a,
-        b,
-        result,
-        move_score,
-        state_score,
-        total_score,
-        "-------"
-    );
-
-    total_score
+    *move_score + *state_score
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
     let rps_matches: Vec<(&Move, &Move)> = input.lines().map(parse_match).collect();
-    dbg!(&rps_matches);
     let points: u32 = rps_matches
         .iter()
         .map(|(a, b)| calculate_match_score((a, b)))
@@ -176,30 +161,23 @@ pub fn part_two(input: &str) -> Option<u32> {
         .map(parse_match_2)
         .map(|m| m.get_score().unwrap_or(0))
         .sum();
-    dbg!(items);
 
     Some(items)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_two(&input), Some(12));
     }
 }
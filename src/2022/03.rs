@@ -26,7 +26,6 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<u32> {
     let rucksacks: Vec<&str> = input.lines().collect();
-    dbg!(&rucksacks);
     let mut badge_ids: Vec<char> = Vec::new();
 
     for chunk in &rucksacks.into_iter().chunks(3) {
@@ -41,25 +40,19 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(result)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_two(&input), Some(70));
     }
 }
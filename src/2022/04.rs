@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+
+use advent_of_code::Error;
+
+type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+fn expand_range(input: &str, range: &str) -> Result<RangeInclusive<u32>, Error> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| Error::parse(input, range, "expected a range like 2-4"))?;
+    let section = |n: &str| {
+        n.parse::<u32>()
+            .map_err(|e| Error::parse(input, n, e.to_string()))
+    };
+    Ok(section(start)?..=section(end)?)
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<Pair>, Error> {
+    input
+        .lines()
+        .map(|pair| {
+            let (range_a, range_b) = pair
+                .split_once(',')
+                .ok_or_else(|| Error::parse(input, pair, "expected two ranges separated by ','"))?;
+            Ok((expand_range(input, range_a)?, expand_range(input, range_b)?))
+        })
+        .collect()
+}
+
+fn range_fully_contains_range(
+    range_a: &RangeInclusive<u32>,
+    range_b: &RangeInclusive<u32>,
+) -> bool {
+    range_a.contains(range_b.start()) && range_a.contains(range_b.end())
+}
+
+fn range_contains_range(range_a: &RangeInclusive<u32>, range_b: &RangeInclusive<u32>) -> bool {
+    range_a.contains(range_b.start()) || range_a.contains(range_b.end())
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let result: usize = parse_pairs(input)?
+        .iter()
+        .map(|(range_a, range_b)| {
+            range_fully_contains_range(range_a, range_b)
+                || range_fully_contains_range(range_b, range_a)
+        })
+        .filter(|&v| v)
+        .count();
+
+    Ok(result as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let result: usize = parse_pairs(input)?
+        .iter()
+        .map(|(range_a, range_b)| {
+            range_contains_range(range_a, range_b) || range_contains_range(range_b, range_a)
+        })
+        .filter(|&v| v)
+        .count();
+
+    Ok(result as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_one(&input), Ok(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_two(&input), Ok(4));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            part_one("2-4,6-8\n2-3;4-5\n"),
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                message: "expected two ranges separated by ','".to_owned()
+            })
+        );
+        assert_eq!(
+            part_two("2-4,6-x\n").unwrap_err().to_string(),
+            "parse error at line 1, column 7: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_largest_section() {
+        assert_eq!(part_one("4294967295-4294967295,1-4294967295\n"), Ok(1));
+    }
+}
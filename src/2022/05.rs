@@ -0,0 +1,178 @@
+use std::collections::VecDeque;
+
+use advent_of_code::Error;
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug)]
+struct Move {
+    quantity: u32,
+    start: usize,
+    end: usize,
+}
+
+fn parse_start_state(input: &str, stacks_str: &str) -> Result<Vec<VecDeque<char>>, Error> {
+    let lines: Vec<&str> = stacks_str.lines().collect();
+
+    let (number_line_idx, number_line) = lines
+        .iter()
+        .enumerate()
+        .find(|(_, l)| l.contains('1'))
+        .ok_or_else(|| Error::parse(input, stacks_str, "expected a line of stack numbers"))?;
+    let num_columns = number_line
+        .trim()
+        .chars()
+        .last()
+        .and_then(|c| c.to_digit(10))
+        .ok_or_else(|| Error::parse(input, number_line, "expected a line of stack numbers"))?;
+
+    let mut stacks: Vec<VecDeque<char>> = Vec::new();
+    for _ in 0..num_columns {
+        stacks.push(VecDeque::new());
+    }
+
+    for line in lines.iter().take(number_line_idx) {
+        for (idx, chars) in (&line.chars().chunks(4)).into_iter().enumerate() {
+            let stack = stacks
+                .get_mut(idx)
+                .ok_or_else(|| Error::parse(input, line, "more crates than stacks"))?;
+            let elf_crate: String = chars.collect();
+            let elf_crate = elf_crate.trim().replace(['[', ']'], "").chars().last();
+            if let Some(c) = elf_crate {
+                stack.push_back(c);
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_instructions(input: &str, instructions_str: &str) -> Result<Vec<Move>, Error> {
+    instructions_str
+        .lines()
+        .map(|l| {
+            let error = || Error::parse(input, l, "expected a move like \"move 1 from 2 to 1\"");
+            let (quantity, start, end) = l
+                .split(' ')
+                .filter_map(|sub| sub.parse::<u32>().ok())
+                .collect_tuple()
+                .ok_or_else(error)?;
+            Ok(Move {
+                quantity,
+                start: (start as usize).checked_sub(1).ok_or_else(error)?,
+                end: (end as usize).checked_sub(1).ok_or_else(error)?,
+            })
+        })
+        .collect()
+}
+
+fn take_crate(operation: Move, stacks: &mut [VecDeque<char>]) -> Result<char, Error> {
+    stacks
+        .get_mut(operation.start)
+        .and_then(|stack| stack.pop_front())
+        .ok_or_else(|| {
+            Error::invalid_state(format!(
+                "no crate to move from stack {}",
+                operation.start + 1
+            ))
+        })
+}
+
+fn target_stack(
+    operation: Move,
+    stacks: &mut [VecDeque<char>],
+) -> Result<&mut VecDeque<char>, Error> {
+    stacks
+        .get_mut(operation.end)
+        .ok_or_else(|| Error::invalid_state(format!("there is no stack {}", operation.end + 1)))
+}
+
+fn make_move(operation: Move, stacks: &mut [VecDeque<char>]) -> Result<(), Error> {
+    for _ in 0..operation.quantity {
+        let c = take_crate(operation, stacks)?;
+        target_stack(operation, stacks)?.push_front(c);
+    }
+    Ok(())
+}
+
+fn make_move_9001(operation: Move, stacks: &mut [VecDeque<char>]) -> Result<(), Error> {
+    let mut stack: VecDeque<char> = VecDeque::new();
+    for _ in 0..operation.quantity {
+        let c = take_crate(operation, stacks)?;
+        stack.push_front(c);
+    }
+    let target = target_stack(operation, stacks)?;
+    for c in stack {
+        target.push_front(c)
+    }
+    Ok(())
+}
+
+fn split_input(input: &str) -> Result<(&str, &str), Error> {
+    input.split_once("\n\n").ok_or_else(|| {
+        Error::parse(
+            input,
+            &input[input.len()..],
+            "expected a blank line between the stacks and the moves",
+        )
+    })
+}
+
+pub fn part_one(input: &str) -> Result<String, Error> {
+    let (start_stack_str, instructions_str) = split_input(input)?;
+    let mut stacks = parse_start_state(input, start_stack_str)?;
+    let instructions = parse_instructions(input, instructions_str)?;
+    for instruction in instructions {
+        make_move(instruction, &mut stacks)?;
+    }
+
+    let result: String = stacks.iter().filter_map(|c| c.front()).collect();
+
+    Ok(result)
+}
+
+pub fn part_two(input: &str) -> Result<String, Error> {
+    let (start_stack_str, instructions_str) = split_input(input)?;
+    let mut stacks = parse_start_state(input, start_stack_str)?;
+    let instructions = parse_instructions(input, instructions_str)?;
+    for instruction in instructions {
+        make_move_9001(instruction, &mut stacks)?;
+    }
+
+    let result: String = stacks.iter().filter_map(|c| c.front()).collect();
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_one(&input), Ok("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_two(&input), Ok("MCD".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(
+            part_one(&input.replace("move 3", "move 4")),
+            Err(Error::invalid_state("no crate to move from stack 1"))
+        );
+        assert_eq!(
+            part_two(&input.replace("from 2 to 1", "from two to 1")),
+            Err(Error::Parse {
+                line: 6,
+                column: 1,
+                message: "expected a move like \"move 1 from 2 to 1\"".to_owned()
+            })
+        );
+    }
+}
@@ -22,12 +22,7 @@ impl<'a> Dir<'a> {
 
     fn size(&self) -> u32 {
         let total_files = self.files.borrow().iter().map(|f| f.filesize).sum::<u32>();
-        let total_dirs = self
-            .dirs
-            .borrow()
-            .iter()
-            .map(|(_, d)| d.size())
-            .sum::<u32>();
+        let total_dirs = self.dirs.borrow().values().map(|d| d.size()).sum::<u32>();
         total_files + total_dirs
     }
 
@@ -35,8 +30,8 @@ impl<'a> Dir<'a> {
         let mut child_sizes: Vec<u32> = self
             .dirs
             .borrow()
-            .iter()
-            .flat_map(|(_, d)| d.sizes())
+            .values()
+            .flat_map(|d| d.sizes())
             .collect();
         child_sizes.push(self.size());
         child_sizes
@@ -116,34 +111,26 @@ pub fn part_two(input: &str) -> Option<u32> {
     let total_used_space = root.size();
     let unused_space = total_disk_space - total_used_space;
     let space_to_free = space_needed - unused_space;
-    dbg!(total_used_space, unused_space, space_to_free);
     let sizes = root.sizes();
     let candidates: Vec<&u32> = sizes.iter().filter(|&&s| s > space_to_free).collect();
-    dbg!(&candidates);
     let result = candidates.iter().min().unwrap();
 
     Some(**result)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
@@ -0,0 +1,61 @@
+use advent_of_code::grid::Grid;
+use advent_of_code::point::Direction;
+
+fn parse_input(input: &str) -> Option<Grid<u32>> {
+    Grid::parse(input, |c| c.to_digit(10)).ok()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse_input(input)?;
+
+    let visible = grid
+        .iter()
+        .filter(|(point, height)| {
+            Direction::ALL.iter().any(|direction| {
+                grid.ray(*point, direction.offset())
+                    .all(|(_, other)| other < *height)
+            })
+        })
+        .count();
+
+    Some(visible as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse_input(input)?;
+
+    grid.iter()
+        .map(|(point, height)| {
+            Direction::ALL
+                .iter()
+                .map(|direction| {
+                    let mut distance = 0;
+                    for (_, other) in grid.ray(point, direction.offset()) {
+                        distance += 1;
+                        if other >= height {
+                            break;
+                        }
+                    }
+                    distance
+                })
+                .product()
+        })
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
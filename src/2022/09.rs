@@ -0,0 +1,181 @@
+use advent_of_code::days::Animate;
+use advent_of_code::point::{Direction, Point};
+use advent_of_code::viz::{Animator, Scene, Viewport};
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete::newline, multi::separated_list1,
+    sequence::separated_pair, Finish, IResult,
+};
+
+#[derive(Debug)]
+struct Operation {
+    direction: Direction,
+    steps: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Knot {
+    point: Point,
+    visited: HashSet<Point>,
+}
+
+impl Default for Knot {
+    fn default() -> Self {
+        let mut v = Self {
+            point: Point::default(),
+            visited: HashSet::new(),
+        };
+        v.visited.insert(Point::default());
+        v
+    }
+}
+
+impl Knot {
+    fn move_knot(&mut self, direction: Direction) {
+        self.point += direction.offset();
+    }
+
+    fn is_adjacent_or_diagonal(&self, knot_b: &Self) -> bool {
+        self.point.chebyshev_distance(&knot_b.point) <= 1
+    }
+
+    fn move_towards(&mut self, knot_b: &Self) {
+        if self.is_adjacent_or_diagonal(knot_b) {
+            return;
+        }
+        // one step on each axis the knots differ on
+        self.point += (knot_b.point - self.point).signum();
+
+        self.visited.insert(self.point);
+    }
+}
+
+#[derive(Debug)]
+struct Rope {
+    knots: Vec<Knot>,
+}
+
+impl Rope {
+    fn new(num_knots: u32) -> Self {
+        Self {
+            knots: (0..num_knots).map(|_| Knot::default()).collect_vec(),
+        }
+    }
+    fn make_move(&mut self, direction: Direction) {
+        self.knots.first_mut().unwrap().move_knot(direction);
+
+        for i in 0..(self.knots.len() - 1) {
+            if let Some(knot_to_move_to) = self.knots.get(i).cloned() {
+                if let Some(knot_to_move) = self.knots.get_mut(i + 1) {
+                    knot_to_move.move_towards(&knot_to_move_to);
+                }
+            }
+        }
+    }
+
+    fn num_visited(&self, index: usize) -> u32 {
+        self.knots.get(index).unwrap().visited.len() as u32
+    }
+}
+
+/// Draws the knots like the puzzle does, on top of the start and the places the tail visited.
+impl Scene for Rope {
+    fn cell(&self, point: Point) -> char {
+        let tail = self.knots.len() - 1;
+        match self.knots.iter().position(|knot| knot.point == point) {
+            Some(0) => 'H',
+            Some(1) if tail == 1 => 'T',
+            Some(i) => char::from_digit(i as u32, 10).unwrap_or('T'),
+            None if point == Point::default() => 's',
+            None if self.knots[tail].visited.contains(&point) => '#',
+            None => '.',
+        }
+    }
+
+    fn bounds(&self) -> Viewport {
+        let knots = self.knots.iter().map(|knot| knot.point);
+        let visited = self.knots.last().into_iter().flat_map(|knot| &knot.visited);
+        Viewport::around(knots.chain(visited.copied())).unwrap_or_default()
+    }
+}
+
+fn operation(input: &str) -> IResult<&str, Operation> {
+    let (input, (op, steps)) = separated_pair(
+        alt((tag("L"), tag("R"), tag("U"), tag("D"))),
+        tag(" "),
+        nom::character::complete::u32,
+    )(input)?;
+
+    let direction = match op {
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        _ => unreachable!("invalid direction"),
+    };
+    Ok((input, Operation { direction, steps }))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Operation>> {
+    separated_list1(newline, operation)(input)
+}
+
+/// Moves a rope of `num_knots` knots and returns the number of places its tail visited.
+fn simulate(input: &str, num_knots: u32, animator: &mut Animator) -> u32 {
+    let (_, operations) = parse_input(input).finish().unwrap();
+    let mut rope = Rope::new(num_knots);
+
+    for operation in operations.iter() {
+        for _ in 0..operation.steps {
+            rope.make_move(operation.direction);
+            animator.show(&rope);
+        }
+    }
+
+    rope.num_visited(num_knots as usize - 1)
+}
+
+/// Moves the rope of a part again with `--animate`.
+pub const ANIMATE: Option<Animate> = Some(|input, part, animator| {
+    simulate(input, if part == 1 { 2 } else { 10 }, animator);
+});
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(simulate(input, 2, &mut Animator::off()))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(simulate(input, 10, &mut Animator::off()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 9);
+        assert_eq!(part_one(&input), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 9);
+        assert_eq!(part_two(&input), Some(1));
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = advent_of_code::read_file("examples", 2022, 9);
+        let viewport = Viewport::new(Point::new(0, -4), 6, 5);
+        let mut animator = Animator::capture().with_viewport(viewport);
+        ANIMATE.unwrap()(&input, 1, &mut animator);
+
+        let frames = animator.frames();
+        assert_eq!(frames.len(), 24);
+        assert_eq!(frames[0], "......\n......\n......\n......\nTH....");
+        assert_eq!(frames[23], "..##..\n...##.\n.TH##.\n....#.\ns###..");
+    }
+}
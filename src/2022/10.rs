@@ -1,5 +1,10 @@
 use std::{fmt, fmt::Display};
 
+use advent_of_code::days::Animate;
+use advent_of_code::grid::Grid;
+use advent_of_code::point::Point;
+use advent_of_code::viz::Animator;
+
 use nom::{
     branch::alt, bytes::complete::tag, character::complete::newline, multi::separated_list1,
     sequence::preceded, Finish, IResult,
@@ -73,7 +78,7 @@ impl Iterator for Computer {
 
 #[derive(Debug)]
 struct Crt {
-    screen: [[char; 40]; 6],
+    screen: Grid<char>,
     current_row: usize,
     current_col: usize,
 }
@@ -81,14 +86,17 @@ struct Crt {
 impl Crt {
     fn new() -> Self {
         Self {
-            screen: [['.'; 40]; 6],
+            screen: Grid::new(40, 6, '.'),
             current_row: 0,
             current_col: 0,
         }
     }
 
     fn set_pixel(&mut self, row: usize, col: usize) {
-        self.screen[row][col] = '#';
+        self.screen[Point {
+            x: col as i32,
+            y: row as i32,
+        }] = '#';
     }
 }
 
@@ -112,13 +120,7 @@ impl Iterator for Crt {
 
 impl Display for Crt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for row in self.screen {
-            for c in row {
-                write!(f, "{}", c)?;
-            }
-            writeln!(f)?;
-        }
-        write!(f, "")
+        write!(f, "{}", self.screen)
     }
 }
 
@@ -140,7 +142,7 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Operation>> {
 pub fn part_one(input: &str) -> Option<i32> {
     let (_, operations) = parse_input(input).finish().unwrap();
     let computer = Computer::new(operations);
-    let interesting_cycles = vec![20, 60, 100, 140, 180, 220];
+    let interesting_cycles = [20, 60, 100, 140, 180, 220];
     let output: i32 = computer
         .enumerate()
         .filter_map(|(i, register)| {
@@ -156,7 +158,8 @@ pub fn part_one(input: &str) -> Option<i32> {
     Some(output)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+/// Draws the screen pixel by pixel, showing every cycle with `animator`.
+fn draw(input: &str, animator: &mut Animator) -> Crt {
     let (_, operations) = parse_input(input).finish().unwrap();
     let mut computer = Computer::new(operations);
     computer.return_type = ReturnType::EndOfCycle;
@@ -167,30 +170,59 @@ pub fn part_two(input: &str) -> Option<u32> {
         if sprite_start <= pixel.1 as i32 && pixel.1 as i32 <= sprite_end {
             screen.set_pixel(pixel.0, pixel.1);
         }
+        animator.show(&screen.screen);
     }
-    println!("{}", screen);
-    None
+    screen
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// The answer is the picture on the screen, which shows eight capital letters for a real input.
+pub fn part_two(input: &str) -> Option<String> {
+    Some(draw(input, &mut Animator::off()).to_string())
 }
 
+/// Draws the screen of part two again with `--animate`.
+pub const ANIMATE: Option<Animate> = Some(|input, part, animator| {
+    if part == 2 {
+        draw(input, animator);
+    }
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
+    const PICTURE: &str = concat!(
+        "##..##..##..##..##..##..##..##..##..##..\n",
+        "###...###...###...###...###...###...###.\n",
+        "####....####....####....####....####....\n",
+        "#####.....#####.....#####.....#####.....\n",
+        "######......######......######......####\n",
+        "#######.......#######.......#######.....",
+    );
+
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+        let input = advent_of_code::read_file("examples", 2022, 10);
+        assert_eq!(part_two(&input), Some(PICTURE.to_owned()));
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = advent_of_code::read_file("examples", 2022, 10);
+        let mut animator = Animator::capture();
+        ANIMATE.unwrap()(&input, 1, &mut animator);
+        assert!(animator.frames().is_empty());
+        ANIMATE.unwrap()(&input, 2, &mut animator);
+
+        let frames = animator.frames();
+        assert!(frames[0].starts_with("#......."));
+        assert!(frames[1].starts_with("##......"));
+        assert_eq!(frames.last().unwrap(), PICTURE);
     }
 }
@@ -32,7 +32,7 @@ struct Test(u64);
 
 impl Test {
     fn test(&self, value: u64) -> bool {
-        value % self.0 == 0
+        value.is_multiple_of(self.0)
     }
 }
 
@@ -173,25 +173,19 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(result)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_one(&input), Some(10605));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_two(&input), Some(2_713_310_158));
     }
 }
@@ -0,0 +1,200 @@
+use advent_of_code::days::Draw;
+use advent_of_code::grid::Grid;
+use advent_of_code::image::{self, Drawing, Rgb};
+use advent_of_code::point::{Direction, Point};
+use advent_of_code::search::{self, Paths};
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+
+/// How the route is drawn into images.
+const PATH_COLOR: Rgb = Rgb(220, 30, 30);
+
+/// The colour of a height, from green valleys over brown slopes to snowy peaks.
+fn height_color(height: char) -> Rgb {
+    const STOPS: [Rgb; 3] = [Rgb(30, 90, 40), Rgb(140, 110, 70), Rgb(245, 245, 245)];
+    let level = (height as u32).saturating_sub('a' as u32).min(25);
+    let position = level as f32 / 25_f32 * (STOPS.len() - 1) as f32;
+    let stop = (position as usize).min(STOPS.len() - 2);
+    let fraction = position - stop as f32;
+
+    let (from, to) = (STOPS[stop], STOPS[stop + 1]);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * fraction).round() as u8;
+    Rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+}
+
+#[derive(Debug)]
+struct Map {
+    heights: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+impl Map {
+    fn new(input: &str) -> Option<Self> {
+        let tiles = Grid::parse(input, Some).ok()?;
+        let start = tiles.position(|&c| c == 'S')?;
+        let end = tiles.position(|&c| c == 'E')?;
+        let heights = tiles.map(|&c| match c {
+            'S' => 'a',
+            'E' => 'z',
+            c => c,
+        });
+
+        Some(Self {
+            heights,
+            start,
+            end,
+        })
+    }
+
+    /// The tiles one step could have come from on the way up, which are at most one lower.
+    fn get_candidates(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let current_height = self.heights[point];
+        self.heights.neighbors4(point).filter(move |candidate| {
+            let tile_diff = current_height as i32 - self.heights[*candidate] as i32;
+            tile_diff <= 1
+        })
+    }
+
+    /// Searches downhill from the end until a tile matching `is_start` is reached. With `|_| false`,
+    /// this is the number of steps to the end from every tile that can reach it.
+    fn descend(&self, is_start: impl FnMut(&Point) -> bool) -> Paths<Point, usize> {
+        search::bfs([self.end], |point| self.get_candidates(*point), is_start)
+    }
+
+    /// The shortest route up to the end from any tile matching `is_start`, from that tile to the
+    /// end.
+    fn find_path(&self, is_start: impl FnMut(&Point) -> bool) -> Option<Vec<Point>> {
+        let mut path = self.descend(is_start).goal_path()?;
+        path.reverse();
+        Some(path)
+    }
+
+    /// Draws the map with the route as arrows that point the way, like the puzzle's illustration.
+    /// With `color`, the background of every tile shows its height.
+    fn render(&self, path: &[Point], color: bool) -> String {
+        let mut tiles = self.heights.map(|_| '.');
+        for step in path.windows(2) {
+            if let Some(direction) = Direction::between(step[0], step[1]) {
+                tiles[step[0]] = direction.arrow();
+            }
+        }
+        tiles[self.end] = 'E';
+
+        if !color {
+            return tiles.to_string();
+        }
+        let mut output = String::new();
+        for (point, tile) in tiles.iter() {
+            if point.x == 0 && point.y > 0 {
+                output.push('\n');
+            }
+            output.push_str(&height_color(self.heights[point]).ansi_background());
+            if *tile != '.' {
+                output.push_str(ANSI_BOLD);
+            }
+            output.push(*tile);
+            output.push_str(ANSI_RESET);
+        }
+        output
+    }
+
+    /// The map coloured by height with the route on top, 4 pixels per tile.
+    fn image(&self, path: &[Point]) -> Grid<Rgb> {
+        let mut pixels = self.heights.map(|height| height_color(*height));
+        for point in path {
+            pixels[*point] = PATH_COLOR;
+        }
+        image::scale(&pixels, 4)
+    }
+
+    /// The route of a part: from the start in part one, from the closest lowest tile in part two.
+    fn route(&self, part: u8) -> Option<Vec<Point>> {
+        match part {
+            1 => self.find_path(|point| *point == self.start),
+            _ => self.find_path(|point| self.heights[*point] == 'a'),
+        }
+    }
+}
+
+struct Route {
+    map: Map,
+    path: Vec<Point>,
+}
+
+impl Drawing for Route {
+    fn text(&self, color: bool) -> String {
+        self.map.render(&self.path, color)
+    }
+
+    fn pixels(&self) -> Grid<Rgb> {
+        self.map.image(&self.path)
+    }
+}
+
+/// The route of a part, drawn with `--render` and `--image`.
+pub const DRAW: Option<Draw> = Some(|input, part| {
+    let map = Map::new(input)?;
+    let path = map.route(part)?;
+    Some(Box::new(Route { map, path }))
+});
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map = Map::new(input)?;
+    Some(map.route(1)?.len() as u32 - 1)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let map = Map::new(input)?;
+    Some(map.route(2)?.len() as u32 - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 12);
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 12);
+        assert_eq!(part_two(&input), Some(29));
+    }
+
+    #[test]
+    fn test_find_path() {
+        let input = advent_of_code::read_file("examples", 2022, 12);
+        let map = Map::new(&input).unwrap();
+
+        let path = map.route(1).unwrap();
+        assert_eq!(path.first(), Some(&map.start));
+        assert_eq!(path.last(), Some(&map.end));
+        assert!(path.windows(2).all(|step| {
+            step[0].manhattan_distance(&step[1]) == 1
+                && map.heights[step[1]] as u32 <= map.heights[step[0]] as u32 + 1
+        }));
+
+        assert_eq!(
+            map.render(&path, false),
+            ">>vv<<<<\n..vvv<<^\n..vv>E^^\n..v>>>^^\n..>>>>>^"
+        );
+        let colored = map.render(&path, true);
+        assert!(colored.starts_with("\x1b[48;2;30;90;40m\x1b[1m>\x1b[0m"));
+        assert_eq!(colored.lines().count(), 5);
+
+        let drawing = DRAW.unwrap()(&input, 2).unwrap();
+        assert_eq!(drawing.text(false).lines().next(), Some("...v<<<<"));
+        assert_eq!(
+            (drawing.pixels().width(), drawing.pixels().height()),
+            (32, 20)
+        );
+        assert!(DRAW.unwrap()("", 1).is_none());
+
+        let distances = map.descend(|_| false);
+        assert_eq!(distances.cost(&map.start), Some(31));
+        assert_eq!(distances.cost(&map.end), Some(0));
+    }
+}
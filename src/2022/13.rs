@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 
+use advent_of_code::{error::parse_all, Error};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -83,8 +84,8 @@ fn parse_input(input: &str) -> IResult<&str, Vec<(PacketElement, PacketElement)>
     separated_list1(tag("\n\n"), parse_packet)(input)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (_, packets) = parse_input(input).unwrap();
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let packets = parse_all(input, parse_input)?;
     let mut valid_packets: Vec<u32> = vec![];
     for (idx, (left, right)) in packets.iter().enumerate() {
         let idx = idx as u32 + 1;
@@ -94,11 +95,11 @@ pub fn part_one(input: &str) -> Option<u32> {
     }
     let result: u32 = valid_packets.iter().sum();
 
-    Some(result)
+    Ok(result)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (_, packets) = parse_input(input).unwrap();
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let packets = parse_all(input, parse_input)?;
     let mut packets: Vec<_> = packets.into_iter().flat_map(|(a, b)| vec![a, b]).collect();
     let packet_1 = PacketElement::List(vec![PacketElement::List(vec![PacketElement::Value(2)])]);
     let packet_2 = PacketElement::List(vec![PacketElement::List(vec![PacketElement::Value(6)])]);
@@ -116,13 +117,7 @@ pub fn part_two(input: &str) -> Option<u32> {
             }
         })
         .product();
-    Some(result)
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -131,13 +126,25 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
+        let input = advent_of_code::read_file("examples", 2022, 13);
+        assert_eq!(part_one(&input), Ok(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
+        let input = advent_of_code::read_file("examples", 2022, 13);
+        assert_eq!(part_two(&input), Ok(140));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            part_one("[1,1,3]\n[1,1,5]\n\n[[1],[2,3,4]]\n[[1],4\n"),
+            Err(Error::Parse {
+                line: 4,
+                column: 1,
+                message: "unexpected input".to_owned()
+            })
+        );
     }
 }
@@ -0,0 +1,254 @@
+use advent_of_code::{
+    days::Animate,
+    error::parse_all,
+    point::{Compass, Point},
+    viz::{Animator, Scene, Viewport},
+    Error,
+};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i32 as nom_i32, newline},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+/// Where the sand pours in.
+const SOURCE: Point = Point::new(500, 0);
+
+pub fn parse_point(input: &str) -> IResult<&str, Point> {
+    let (input, (x, y)) = separated_pair(nom_i32, tag(","), nom_i32)(input)?;
+    Ok((input, Point::new(x, y)))
+}
+
+pub fn parse_line(input: &str) -> IResult<&str, Vec<Point>> {
+    let (input, points) = separated_list1(tag(" -> "), parse_point)(input)?;
+
+    let points: Vec<Point> = points
+        .into_iter()
+        .tuple_windows()
+        .flat_map(|(a, b)| {
+            let step = (b - a).signum();
+            let length = a.chebyshev_distance(&b) as i32;
+            (0..=length).map(move |i| a + step * i)
+        })
+        .collect();
+
+    Ok((input, points))
+}
+
+pub fn parse_input(input: &str) -> IResult<&str, BTreeSet<Point>> {
+    let (input, lines) = separated_list1(newline, parse_line)(input)?;
+    let output: BTreeSet<Point> = lines.into_iter().flatten().collect();
+    Ok((input, output))
+}
+
+fn lowest_rock(grid: &BTreeSet<Point>) -> Result<i32, Error> {
+    grid.iter()
+        .map(|point| point.y)
+        .max()
+        .ok_or_else(|| Error::invalid_state("the scan contains no rock"))
+}
+
+/// The cave as the puzzle draws it, with the rock, the settled sand and the source.
+struct Cave<'a> {
+    rock: &'a BTreeSet<Point>,
+    filled: &'a BTreeSet<Point>,
+    floor: Option<i32>,
+}
+
+impl Scene for Cave<'_> {
+    fn cell(&self, point: Point) -> char {
+        if self.rock.contains(&point) || Some(point.y) == self.floor {
+            '#'
+        } else if self.filled.contains(&point) {
+            'o'
+        } else if point == SOURCE {
+            '+'
+        } else {
+            '.'
+        }
+    }
+
+    fn bounds(&self) -> Viewport {
+        let floor = self.floor.map(|y| Point::new(SOURCE.x, y));
+        let points = self.filled.iter().copied().chain([SOURCE]).chain(floor);
+        Viewport::around(points).unwrap_or_default()
+    }
+}
+
+/// Pours sand until it falls into the abyss, showing every unit that comes to rest.
+fn pour_sand(input: &str, animator: &mut Animator) -> Result<u32, Error> {
+    let mut grid = parse_all(input, parse_input)?;
+    let rock = grid.clone();
+    let rock_quantity = grid.len();
+    let lowest_rock = lowest_rock(&grid)?;
+
+    let mut current_sand = SOURCE;
+
+    loop {
+        if current_sand.y > lowest_rock {
+            break;
+        }
+        let down = current_sand + Compass::S.offset();
+        let down_left = current_sand + Compass::SW.offset();
+        let down_right = current_sand + Compass::SE.offset();
+
+        if !grid.contains(&down) {
+            current_sand = down;
+        } else if !grid.contains(&down_left) {
+            current_sand = down_left;
+        } else if !grid.contains(&down_right) {
+            current_sand = down_right;
+        } else {
+            grid.insert(current_sand);
+            current_sand = SOURCE;
+            animator.show(&Cave {
+                rock: &rock,
+                filled: &grid,
+                floor: None,
+            });
+        }
+    }
+    let result = grid.len() - rock_quantity;
+
+    Ok(result as u32)
+}
+
+/// Pours sand onto the floor until it blocks the source, showing every unit that comes to rest.
+fn pour_sand_onto_floor(input: &str, animator: &mut Animator) -> Result<u32, Error> {
+    let mut grid = parse_all(input, parse_input)?;
+    let rock = grid.clone();
+    let rock_quantity = grid.len();
+    let lowest_rock = lowest_rock(&grid)?;
+    let lowest_rock = lowest_rock + 2;
+
+    let mut current_sand = SOURCE;
+
+    while !grid.contains(&SOURCE) {
+        let down = current_sand + Compass::S.offset();
+        let down_left = current_sand + Compass::SW.offset();
+        let down_right = current_sand + Compass::SE.offset();
+
+        if down.y == lowest_rock {
+            grid.insert(current_sand);
+            current_sand = SOURCE;
+            animator.show(&Cave {
+                rock: &rock,
+                filled: &grid,
+                floor: Some(lowest_rock),
+            });
+        } else if !grid.contains(&down) {
+            current_sand = down;
+        } else if !grid.contains(&down_left) {
+            current_sand = down_left;
+        } else if !grid.contains(&down_right) {
+            current_sand = down_right;
+        } else {
+            grid.insert(current_sand);
+            current_sand = SOURCE;
+            animator.show(&Cave {
+                rock: &rock,
+                filled: &grid,
+                floor: Some(lowest_rock),
+            });
+        }
+    }
+    let result = grid.len() - rock_quantity;
+
+    Ok(result as u32)
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    pour_sand(input, &mut Animator::off())
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    pour_sand_onto_floor(input, &mut Animator::off())
+}
+
+/// Pours the sand of a part again with `--animate`. Errors were already reported by the solver.
+pub const ANIMATE: Option<Animate> = Some(|input, part, animator| {
+    let _ = match part {
+        1 => pour_sand(input, animator),
+        _ => pour_sand_onto_floor(input, animator),
+    };
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 14);
+        assert_eq!(part_one(&input), Ok(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 14);
+        assert_eq!(part_two(&input), Ok(93));
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = advent_of_code::read_file("examples", 2022, 14);
+        let viewport = Viewport::new(Point::new(494, 0), 10, 10);
+        let mut animator = Animator::capture().with_viewport(viewport);
+        pour_sand(&input, &mut animator).unwrap();
+
+        let frames = animator.frames();
+        assert_eq!(frames.len(), 24);
+        assert_eq!(
+            frames[0],
+            "......+...\n..........\n..........\n..........\n....#...##\n\
+             ....#...#.\n..###...#.\n........#.\n......o.#.\n#########."
+        );
+        assert_eq!(
+            frames[23],
+            "......+...\n..........\n......o...\n.....ooo..\n....#ooo##\n\
+             ...o#ooo#.\n..###ooo#.\n....oooo#.\n.o.ooooo#.\n#########."
+        );
+
+        let mut animator = Animator::capture();
+        pour_sand_onto_floor(&input, &mut animator).unwrap();
+        assert_eq!(animator.frames().len(), 93);
+        assert_eq!(
+            animator.frames()[92],
+            concat!(
+                "..........o..........\n",
+                ".........ooo.........\n",
+                "........ooooo........\n",
+                ".......ooooooo.......\n",
+                "......oo#ooo##o......\n",
+                ".....ooo#ooo#ooo.....\n",
+                "....oo###ooo#oooo....\n",
+                "...oooo.oooo#ooooo...\n",
+                "..oooooooooo#oooooo..\n",
+                ".ooo#########ooooooo.\n",
+                "ooooo.......ooooooooo\n",
+                "#####################",
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            part_one("498,4 -> 498,6\n503,4 -> 502;4\n"),
+            Err(Error::Parse {
+                line: 2,
+                column: 7,
+                message: "unexpected input".to_owned()
+            })
+        );
+        assert_eq!(
+            part_two("498,4\n"),
+            Err(Error::invalid_state("the scan contains no rock"))
+        );
+    }
+}
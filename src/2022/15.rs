@@ -0,0 +1,239 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    ops::{ControlFlow, RangeInclusive},
+};
+
+// Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+use advent_of_code::{
+    days::Animate,
+    error::parse_all,
+    point::Point,
+    viz::{Animator, Scene, Viewport},
+    Error,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{i32 as nom_i32, newline},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+type Sensor = Point;
+type Beacon = Point;
+
+// x=2, y=18
+fn parse_point(input: &str) -> IResult<&str, Point> {
+    let (input, (x, y)) =
+        separated_pair(preceded(tag("x="), nom_i32), tag(", y="), nom_i32)(input)?;
+    Ok((input, Point { x, y }))
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Sensor, Beacon)> {
+    let (input, (sensor, beacon)) = preceded(
+        tag("Sensor at "),
+        separated_pair(parse_point, tag(": closest beacon is at "), parse_point),
+    )(input)?;
+
+    Ok((input, (sensor, beacon)))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(Sensor, Beacon)>> {
+    separated_list1(newline, parse_line)(input)
+}
+
+/// The sensors and beacons, and the positions the first `shown` sensors rule out.
+struct Coverage<'a> {
+    sensors: &'a [(Sensor, Beacon)],
+    shown: usize,
+}
+
+impl Scene for Coverage<'_> {
+    fn cell(&self, point: Point) -> char {
+        if self.sensors.iter().any(|(sensor, _)| *sensor == point) {
+            'S'
+        } else if self.sensors.iter().any(|(_, beacon)| *beacon == point) {
+            'B'
+        } else if self.sensors[..self.shown].iter().any(|(sensor, beacon)| {
+            sensor.manhattan_distance(&point) <= sensor.manhattan_distance(beacon)
+        }) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    fn bounds(&self) -> Viewport {
+        let corners = self.sensors.iter().flat_map(|(sensor, beacon)| {
+            let distance = sensor.manhattan_distance(beacon) as i32;
+            [
+                Point::new(sensor.x - distance, sensor.y),
+                Point::new(sensor.x + distance, sensor.y),
+                Point::new(sensor.x, sensor.y - distance),
+                Point::new(sensor.x, sensor.y + distance),
+            ]
+        });
+        Viewport::around(corners).unwrap_or_default()
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    count_covered(input, &mut Animator::off())
+}
+
+/// Adds the sensors of part one again with `--animate`. Errors were already reported by the
+/// solver.
+pub const ANIMATE: Option<Animate> = Some(|input, part, animator| {
+    if part == 1 {
+        let _ = count_covered(input, animator);
+    }
+});
+
+/// Counts the positions in the interesting row where the beacon cannot be, showing the area each
+/// sensor covers as it is added.
+fn count_covered(input: &str, animator: &mut Animator) -> Result<u32, Error> {
+    let mut grid: BTreeMap<i32, Vec<RangeInclusive<i32>>> = BTreeMap::new();
+    let mut beacon_y_count: BTreeMap<i32, HashSet<Point>> = BTreeMap::new();
+    let sensors = parse_all(input, parse_input)?;
+
+    for (i, (sensor, beacon)) in sensors.iter().enumerate() {
+        let distance = sensor.manhattan_distance(beacon) as i32;
+        beacon_y_count
+            .entry(beacon.y)
+            .and_modify(|v| {
+                v.insert(*beacon);
+            })
+            .or_default();
+        for delta_y in -distance..=distance {
+            let current_y = sensor.y + delta_y;
+            let half_x_range = distance - delta_y.abs();
+            let range = (sensor.x - half_x_range)..=(sensor.x + half_x_range);
+            grid.entry(current_y)
+                .and_modify(|v| v.push(range.clone()))
+                .or_default();
+        }
+        animator.show(&Coverage {
+            sensors: &sensors,
+            shown: i + 1,
+        });
+    }
+
+    // let interesting_row = 2000000;
+    let interesting_row = 10;
+
+    let row = grid
+        .get(&interesting_row)
+        .ok_or_else(|| Error::invalid_state(format!("no sensor covers row {}", interesting_row)))?;
+    let beacons = beacon_y_count
+        .get(&interesting_row)
+        .map(|v| v.len())
+        .unwrap_or(0_usize);
+    let result = row
+        .iter()
+        .fold(BTreeSet::new(), |mut acc, v| {
+            acc.extend(v.clone());
+            acc
+        })
+        .len()
+        - beacons;
+
+    Ok(result as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u64, Error> {
+    // let max_range = 4_000_000;
+    let max_range = 20;
+    let mut grid: BTreeMap<i32, Vec<RangeInclusive<i32>>> = BTreeMap::new();
+    let sensors = parse_all(input, parse_input)?;
+
+    for (sensor, beacon) in sensors.iter() {
+        let distance = sensor.manhattan_distance(beacon) as i32;
+        for delta_y in -distance..=distance {
+            let current_y = sensor.y + delta_y;
+            if current_y < 0 || current_y > max_range {
+                continue;
+            }
+            let half_x_range = distance - delta_y.abs();
+            let range = 0.max(sensor.x - half_x_range)..=max_range.min(sensor.x + half_x_range);
+            grid.entry(current_y)
+                .and_modify(|v| {
+                    v.push(range.clone());
+                    v.sort_by(|a, b| a.start().cmp(b.start()));
+                })
+                .or_insert_with(|| vec![range.clone()]);
+        }
+    }
+
+    let distress_beacon_location: Point = grid
+        .into_iter()
+        .filter_map(|(y, v)| {
+            match v.iter().try_fold(0..=0, |acc, r| {
+                if r.start() <= &(*acc.end() + 1) {
+                    ControlFlow::Continue(*acc.start()..=*r.end().max(acc.end()))
+                } else {
+                    // println!("{:?} - {:?} - {:?}", &y, &acc, &r);
+                    ControlFlow::Break((acc.end() + 1)..=(r.start() - 1))
+                }
+            }) {
+                ControlFlow::Continue(_) => None,
+                ControlFlow::Break(r) => Some((*r.start(), y)),
+            }
+        })
+        .map(|(x, y)| Point { x, y })
+        .next_back()
+        .ok_or_else(|| Error::invalid_state("no position is left for the distress beacon"))?;
+
+    let result = 4_000_000 * distress_beacon_location.x as u64 + distress_beacon_location.y as u64;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 15);
+        assert_eq!(part_one(&input), Ok(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 15);
+        assert_eq!(part_two(&input), Ok(56000011));
+    }
+
+    #[test]
+    fn test_animation() {
+        let input = advent_of_code::read_file("examples", 2022, 15);
+        let viewport = Viewport::new(Point::new(-10, 0), 50, 23);
+        let mut animator = Animator::capture().with_viewport(viewport);
+        count_covered(&input, &mut animator).unwrap();
+
+        let frames = animator.frames();
+        assert_eq!(frames.len(), 14);
+        let row = |frame: &str| frame.lines().nth(10).unwrap().to_owned();
+        assert_eq!(
+            row(&frames[0]),
+            format!("{}B{}", ".".repeat(12), ".".repeat(37))
+        );
+        assert_eq!(row(&frames[13]).matches('#').count(), 26);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            part_one("Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n"),
+            Err(Error::invalid_state("no sensor covers row 10"))
+        );
+        assert_eq!(
+            part_two("Sensor at x=2, y=18: closest beacon at x=-2, y=15\n"),
+            Err(Error::Parse {
+                line: 1,
+                column: 20,
+                message: "unexpected input, expected tag".to_owned()
+            })
+        );
+    }
+}
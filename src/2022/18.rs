@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use advent_of_code::point::Point3;
+use advent_of_code::search;
+
+use nom::{
+    *,
+    bytes::complete::tag,
+    IResult, multi::separated_list1, character::complete::{i32 as nom_i32, newline}};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Point3>> {
+    separated_list1(newline, separated_list1(tag(","), nom_i32).map(|v| {
+        let (x, y, z) = if let [x, y, z] = v[0..3] { (x, y, z) } else { unreachable!() };
+        Point3::new(x,y,z)
+    }))(input)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (_, input) = parse_input(input).unwrap();
+    let all_points: HashSet<_> = input.iter().collect();
+    let result: usize = input.iter().map(|p| {
+        p.neighbors6().filter(|n| !all_points.contains(n)).count()
+    }).sum();
+    Some(result as u32)
+}
+
+fn bounding_box(points: &Vec<Point3>) -> (Point3, Point3) {
+    // create bounding box
+    let (mut x_min, mut x_max) = (i32::MAX, i32::MIN);
+    let (mut y_min, mut y_max) = (i32::MAX, i32::MIN);
+    let (mut z_min, mut z_max) = (i32::MAX, i32::MIN);
+    for p in points {
+        let (x, y, z) = (p.x, p.y, p.z);
+        (x_min, x_max) = (x.min(x_min), x.max(x_max));
+        (y_min, y_max) = (y.min(y_min), y.max(y_max));
+        (z_min, z_max) = (z.min(z_min), z.max(z_max));
+    }
+    (x_min, x_max) = (x_min - 1, x_max + 1);
+    (y_min, y_max) = (y_min - 1, y_max + 1);
+    (z_min, z_max) = (z_min - 1, z_max + 1);
+    (Point3::new(x_min, y_min, z_min), Point3::new(x_max, y_max, z_max))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (_, input) = parse_input(input).unwrap();
+    let all_points: HashSet<_> = input.iter().collect();
+
+    let (min_point, max_point) = bounding_box(&input);
+    let in_bounds = |p: &Point3| {
+        p.x >= min_point.x && p.x <= max_point.x &&
+        p.y >= min_point.y && p.y <= max_point.y &&
+        p.z >= min_point.z && p.z <= max_point.z
+    };
+    // flood fill the air around the droplet, starting in a corner of the bounding box
+    let air = search::bfs(
+        [min_point],
+        |p| p.neighbors6().filter(|n| in_bounds(n) && !all_points.contains(n)).collect::<Vec<_>>(),
+        |_| false,
+    );
+    // every face of lava that touches the outside air
+    let surface_area = air.costs().keys().map(|p| {
+        p.neighbors6().filter(|n| all_points.contains(n)).count() as u32
+    }).sum();
+    Some(surface_area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 18);
+        assert_eq!(part_one(&input), Some(64));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 18);
+        assert_eq!(part_two(&input), Some(58));
+    }
+}
@@ -55,25 +55,19 @@ pub fn part_two(input: &str) -> Option<i64> {
     Some(result)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 20);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 20);
+        let input = advent_of_code::read_file("examples", 2022, 20);
         assert_eq!(part_one(&input), Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 20);
+        let input = advent_of_code::read_file("examples", 2022, 20);
         assert_eq!(part_two(&input), Some(1623178306));
     }
 }
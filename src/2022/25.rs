@@ -1,6 +1,7 @@
 use nom::{*, multi::many1, branch::alt, bytes::complete::tag};
 
 #[cfg(test)]
+#[allow(clippy::single_component_path_imports)]
 use rstest_reuse;
 
 use std::{str::FromStr, iter::Sum, fmt::Display};
@@ -87,13 +88,8 @@ pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 25);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
+#[allow(unused_imports)]
 mod tests {
     use super::*;
     use rstest::rstest;
@@ -146,13 +142,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 25);
+        let input = advent_of_code::read_file("examples", 2022, 25);
         assert_eq!(part_one(&input), Some("2=-1=0".to_owned()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 25);
+        let input = advent_of_code::read_file("examples", 2022, 25);
         assert_eq!(part_two(&input), None);
     }
 }
@@ -0,0 +1,247 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+use crate::{SolveResult, Status};
+
+/// Folder below `src/YYYY` that holds the recorded answers, one `NN.txt` file per day.
+pub const ANSWERS_FOLDER: &str = "answers";
+
+/// The known-good answers of a day for the real input, or for one of its named input sets.
+///
+/// They are stored as `src/YYYY/answers/NN.txt`, with the answer of part one on the first line and the
+/// answer of part two on the second. An empty line means that no answer was recorded for that part.
+/// Answers that span several lines, like a picture drawn by the puzzle, are stored on one line with
+/// their line breaks written as `\n` and backslashes as `\\`.
+/// The answers for the input set `src/YYYY/inputs/NN/<name>.txt` are kept in
+/// `src/YYYY/answers/NN/<name>.txt`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    /// Path of the answers file of a day, or of one of its input sets.
+    pub fn path(year: i32, day: u8, input_set: Option<&str>) -> PathBuf {
+        match input_set {
+            Some(name) => crate::input::set_path(ANSWERS_FOLDER, year, day, name),
+            None => crate::file_path(ANSWERS_FOLDER, year, day),
+        }
+    }
+
+    /// Reads the recorded answers of a day. A missing file means that nothing was recorded yet.
+    pub fn read(year: i32, day: u8, input_set: Option<&str>) -> io::Result<Self> {
+        match crate::input::read_path(&Self::path(year, day, input_set)) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        let mut lines = contents.lines().map(|line| {
+            let line = line.trim();
+            (!line.is_empty()).then(|| unescape(line))
+        });
+
+        Self {
+            part_one: lines.next().flatten(),
+            part_two: lines.next().flatten(),
+        }
+    }
+
+    pub fn write(&self, year: i32, day: u8, input_set: Option<&str>) -> io::Result<()> {
+        let path = Self::path(year, day, input_set);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Stores the answer of a solved result. Unsolved results keep the previously recorded answer.
+    pub fn record(&mut self, result: &SolveResult) {
+        if result.status != Status::Solved {
+            return;
+        }
+        let answer = result.answer.clone();
+        match result.part {
+            1 => self.part_one = answer,
+            2 => self.part_two = answer,
+            _ => {}
+        }
+    }
+
+    /// Compares a result with the recorded answer of its part and flags it if they differ.
+    ///
+    /// A part that no longer finds an answer is a mismatch too. Parts that failed or were stopped
+    /// keep their status, and only get the expected answer for the report. Without an input there
+    /// is nothing to compare.
+    pub fn verify(&self, mut result: SolveResult) -> SolveResult {
+        let Some(expected) = self.get(result.part) else {
+            return result;
+        };
+        if result.status == Status::NoInput {
+            return result;
+        }
+
+        if matches!(result.status, Status::Solved | Status::Unsolved)
+            && result.answer.as_deref() != Some(expected)
+        {
+            result.status = Status::Mismatch;
+        }
+        result.expected = Some(expected.to_owned());
+
+        result
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{}",
+            escape(self.part_one.as_deref().unwrap_or_default())
+        )?;
+        writeln!(
+            f,
+            "{}",
+            escape(self.part_two.as_deref().unwrap_or_default())
+        )
+    }
+}
+
+pub(crate) fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(line: &str) -> String {
+    let mut answer = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                answer.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                answer.push('\\');
+                chars.next();
+            }
+            _ => answer.push(c),
+        }
+    }
+    answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("24000\n45000\n");
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("45000"));
+
+        let answers = Answers::parse("\nMCD\n");
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.part_two, Some("MCD".to_owned()));
+        assert_eq!(answers.to_string(), "\nMCD\n");
+
+        assert_eq!(Answers::parse(""), Answers::default());
+    }
+
+    #[test]
+    fn test_multiline() {
+        let answers = Answers {
+            part_one: Some("#..#\n#..#".to_owned()),
+            part_two: Some("C:\\n".to_owned()),
+        };
+        assert_eq!(answers.to_string(), "#..#\\n#..#\nC:\\\\n\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("24000\n45000\n");
+
+        let result = answers.verify(SolveResult::solved(
+            2022,
+            1,
+            1,
+            "24000".to_owned(),
+            Duration::ZERO,
+        ));
+        assert_eq!(result.status, Status::Solved);
+        assert_eq!(result.expected, Some("24000".to_owned()));
+
+        let result = answers.verify(SolveResult::solved(
+            2022,
+            1,
+            2,
+            "44999".to_owned(),
+            Duration::ZERO,
+        ));
+        assert_eq!(result.status, Status::Mismatch);
+        assert_eq!(result.expected, Some("45000".to_owned()));
+
+        let result = answers.verify(SolveResult::unsolved(2022, 1, 2, Duration::ZERO));
+        assert_eq!(result.status, Status::Mismatch);
+        assert_eq!(result.expected, Some("45000".to_owned()));
+
+        let result = answers.verify(SolveResult::failed(
+            2022,
+            1,
+            1,
+            crate::Error::invalid_state("no elves"),
+            Duration::ZERO,
+        ));
+        assert_eq!(result.status, Status::Error);
+        assert_eq!(result.expected, Some("24000".to_owned()));
+
+        let result = answers.verify(SolveResult::no_input(2022, 1, 1));
+        assert_eq!(result.status, Status::NoInput);
+        assert_eq!(result.expected, None);
+
+        let result = Answers::default().verify(SolveResult::unsolved(2022, 1, 1, Duration::ZERO));
+        assert_eq!(result.status, Status::Unsolved);
+
+        let result = Answers::default().verify(SolveResult::solved(
+            2022,
+            1,
+            1,
+            "1".to_owned(),
+            Duration::ZERO,
+        ));
+        assert_eq!(result.status, Status::Solved);
+        assert_eq!(result.expected, None);
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::parse("24000\n45000\n");
+        answers.record(&SolveResult::solved(
+            2022,
+            1,
+            1,
+            "1".to_owned(),
+            Duration::ZERO,
+        ));
+        answers.record(&SolveResult::unsolved(2022, 1, 2, Duration::ZERO));
+        assert_eq!(answers.to_string(), "1\n45000\n");
+    }
+}
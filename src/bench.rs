@@ -0,0 +1,284 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::{IntoAnswer, SolveResult, ANSI_ITALIC, ANSI_RESET};
+
+/// Timing statistics over repeated runs of a solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Some(Self {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+
+    fn to_line(self, part: u8) -> String {
+        format!(
+            "{} {} {} {} {} {}",
+            part,
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.stddev.as_nanos()
+        )
+    }
+
+    fn from_line(line: &str) -> Option<(u8, Self)> {
+        let mut fields = line.split_whitespace();
+        let part = fields.next()?.parse().ok()?;
+        let runs = fields.next()?.parse().ok()?;
+        let mut duration = || fields.next()?.parse().ok().map(Duration::from_nanos);
+
+        Some((
+            part,
+            Self {
+                runs,
+                min: duration()?,
+                median: duration()?,
+                mean: duration()?,
+                stddev: duration()?,
+            },
+        ))
+    }
+}
+
+/// The statistics of a benchmarked part, along with the saved baseline to compare them to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Benchmark {
+    pub stats: Stats,
+    pub baseline: Option<Stats>,
+}
+
+impl Benchmark {
+    /// Relative change of the median against the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.median.as_nanos() as f64;
+        if baseline == 0_f64 {
+            return None;
+        }
+        Some((self.stats.median.as_nanos() as f64 - baseline) / baseline * 100_f64)
+    }
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stats = &self.stats;
+        write!(
+            f,
+            "{}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, runs: {}){}",
+            ANSI_ITALIC, stats.median, stats.mean, stats.stddev, stats.min, stats.runs, ANSI_RESET
+        )?;
+        if let (Some(change), Some(baseline)) = (self.change(), self.baseline) {
+            write!(
+                f,
+                "\n{}{:+.2}% against baseline median of {:.2?}{}",
+                ANSI_ITALIC, change, baseline.median, ANSI_RESET
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a part `iterations` times after a short warm-up and collects timing statistics.
+///
+/// The elapsed time of the returned result is the median run.
+pub fn run_part<R: IntoAnswer>(
+    year: i32,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> R,
+    input: &str,
+    iterations: usize,
+) -> SolveResult {
+    let iterations = iterations.max(1);
+
+    let answer = match solver(input).into_answer() {
+        Ok(Some(answer)) => answer,
+        answer => return SolveResult::from_answer(year, day, part, answer, Duration::ZERO),
+    };
+
+    for _ in 1..(iterations / 10).max(1) {
+        solver(input);
+    }
+
+    let samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let timer = Instant::now();
+            solver(input);
+            timer.elapsed()
+        })
+        .collect();
+
+    let stats = Stats::from_samples(&samples).unwrap();
+    let mut result = SolveResult::solved(year, day, part, answer, stats.median);
+    result.benchmark = Some(Benchmark {
+        stats,
+        baseline: None,
+    });
+    result
+}
+
+/// `target/baselines/YYYY/NN.txt`, or `target/baselines/YYYY/NN/<name>.txt` for an input set,
+/// like the answers of a day.
+fn baseline_path(year: i32, day: u8, input_set: Option<&str>) -> PathBuf {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("baselines")
+        .join(year.to_string());
+    match input_set {
+        Some(name) => dir
+            .join(format!("{:02}", day))
+            .join(format!("{}.txt", name)),
+        None => dir.join(format!("{:02}.txt", day)),
+    }
+}
+
+fn read_baselines(year: i32, day: u8, input_set: Option<&str>) -> io::Result<Vec<(u8, Stats)>> {
+    match fs::read_to_string(baseline_path(year, day, input_set)) {
+        Ok(contents) => Ok(contents.lines().filter_map(Stats::from_line).collect()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+/// Reads the saved baseline of a part on the default input or an input set.
+pub fn read_baseline(year: i32, day: u8, part: u8, input_set: Option<&str>) -> Option<Stats> {
+    read_baselines(year, day, input_set)
+        .ok()?
+        .into_iter()
+        .find(|(p, _)| *p == part)
+        .map(|(_, stats)| stats)
+}
+
+/// Saves the statistics of a part as the baseline that later runs are compared to.
+pub fn save_baseline(
+    year: i32,
+    day: u8,
+    part: u8,
+    input_set: Option<&str>,
+    stats: &Stats,
+) -> io::Result<()> {
+    let mut baselines = read_baselines(year, day, input_set)?;
+    baselines.retain(|(p, _)| *p != part);
+    baselines.push((part, *stats));
+    baselines.sort_by_key(|(p, _)| *p);
+
+    let contents: String = baselines
+        .into_iter()
+        .map(|(part, stats)| stats.to_line(part) + "\n")
+        .collect();
+
+    let path = baseline_path(year, day, input_set);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[4, 2, 8, 6])).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2582));
+
+        let stats = Stats::from_samples(&micros(&[3])).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_stats_line() {
+        let stats = Stats::from_samples(&micros(&[1, 2, 3])).unwrap();
+        assert_eq!(Stats::from_line(&stats.to_line(2)), Some((2, stats)));
+        assert_eq!(Stats::from_line("1 2 3"), None);
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = Stats::from_samples(&micros(&[90])).unwrap();
+        let mut benchmark = Benchmark {
+            stats,
+            baseline: None,
+        };
+        assert_eq!(benchmark.change(), None);
+
+        benchmark.baseline = Stats::from_samples(&micros(&[120]));
+        assert_eq!(benchmark.change(), Some(-25_f64));
+    }
+
+    #[test]
+    fn test_baseline_path() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/baselines/2022");
+        assert_eq!(baseline_path(2022, 7, None), dir.join("07.txt"));
+        assert_eq!(
+            baseline_path(2022, 7, Some("alice")),
+            dir.join("07/alice.txt")
+        );
+    }
+
+    #[test]
+    fn test_run_part() {
+        let result = run_part(2022, 1, 1, |input: &str| Some(input.len()), "abc", 5);
+        assert_eq!(result.answer, Some("3".to_owned()));
+        assert_eq!(result.benchmark.unwrap().stats.runs, 5);
+        assert_eq!(result.elapsed, result.benchmark.unwrap().stats.median);
+
+        let result = run_part(2022, 1, 2, |_: &str| None::<u32>, "abc", 5);
+        assert_eq!(result.benchmark, None);
+    }
+}
@@ -0,0 +1,185 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::{self, Client};
+use advent_of_code::puzzle;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+struct Args {
+    day: u8,
+    year: i32,
+    /// A saved puzzle page to read instead of fetching it.
+    html: Option<PathBuf>,
+    /// The `<pre><code>` block (1-based) that becomes `src/YYYY/examples/NN.txt`.
+    example: usize,
+    /// Further blocks to save as `src/YYYY/examples/NN-<n>.txt`.
+    keep: Vec<usize>,
+    /// Overwrite example files that already have content.
+    force: bool,
+    /// Write the proposed example answers into the day's tests.
+    update_tests: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(client::default_year),
+        html: args.opt_value_from_str("--html")?,
+        example: args.opt_value_from_str("--example")?.unwrap_or(1),
+        keep: args.values_from_str("--keep")?,
+        force: args.contains("--force"),
+        update_tests: args.contains("--update-tests"),
+        day: args.free_from_str()?,
+    })
+}
+
+fn exit_with_error(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn relative(path: &Path) -> &Path {
+    path.strip_prefix(env!("CARGO_MANIFEST_DIR"))
+        .unwrap_or(path)
+}
+
+fn read_html(args: &Args) -> Result<String, String> {
+    if let Some(path) = &args.html {
+        return advent_of_code::input::read_path(path).map_err(|e| e.to_string());
+    }
+
+    println!("Fetching puzzle for {} day {}...", args.year, args.day);
+    Client::from_env()
+        .and_then(|client| client.get(&format!("/{}/day/{}", args.year, args.day)))
+        .map_err(|e| format!("Failed to fetch puzzle: {}", e))
+}
+
+/// Writes an example block unless the file already has content and `--force` was not passed.
+fn write_example(path: &Path, example: &str, force: bool) -> Result<(), String> {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+    if !force && !existing.trim().is_empty() {
+        if existing != example {
+            println!(
+                "Kept existing example file \"{}\", pass `--force` to overwrite it",
+                relative(path).display()
+            );
+        }
+        return Ok(());
+    }
+
+    client::write_atomic(path, example)
+        .map_err(|e| format!("Failed to write \"{}\": {}", path.display(), e))?;
+    println!(
+        "Wrote example to \"{}\" ({} lines)",
+        relative(path).display(),
+        example.lines().count()
+    );
+    Ok(())
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let html = read_html(args)?;
+    if puzzle::articles(&html).is_empty() {
+        return Err("The page contains no puzzle description.".to_owned());
+    }
+
+    let markdown_path = puzzle::markdown_path(args.year, args.day);
+    client::write_atomic(&markdown_path, &puzzle::to_markdown(&html))
+        .map_err(|e| format!("Failed to write \"{}\": {}", markdown_path.display(), e))?;
+    println!(
+        "Wrote puzzle description to \"{}\"",
+        relative(&markdown_path).display()
+    );
+
+    let examples = puzzle::examples(&html);
+    for block in [args.example].iter().chain(&args.keep) {
+        if !examples.is_empty() && !(1..=examples.len()).contains(block) {
+            return Err(format!(
+                "Block {} is out of range, the page has {} example blocks",
+                block,
+                examples.len()
+            ));
+        }
+    }
+
+    // Only the selected block becomes the day's example, and only the blocks passed to `--keep`
+    // are saved next to it. The others are listed so they can be picked in another run.
+    for (idx, example) in examples.iter().enumerate() {
+        let block = idx + 1;
+        let path = advent_of_code::file_path("examples", args.year, args.day);
+        if block == args.example {
+            write_example(&path, example, args.force)?;
+        } else if args.keep.contains(&block) {
+            let path = path.with_file_name(format!("{:02}-{}.txt", args.day, block));
+            write_example(&path, example, args.force)?;
+        } else {
+            println!(
+                "Skipped block {} ({} lines, starts with \"{}\"), pass `--example {}` or `--keep {}` to save it",
+                block,
+                example.lines().count(),
+                example.lines().next().unwrap_or_default(),
+                block,
+                block
+            );
+        }
+    }
+
+    let module_path = advent_of_code::year_dir(args.year).join(format!("{:02}.rs", args.day));
+    let original = fs::read_to_string(&module_path).ok();
+    let mut module = original.clone();
+
+    for (idx, answer) in puzzle::example_answers(&html).into_iter().enumerate() {
+        let part = idx as u8 + 1;
+        let Some(answer) = answer else {
+            continue;
+        };
+        let expected = puzzle::expected_value(&answer);
+        let function = if part == 1 { "part_one" } else { "part_two" };
+        println!(
+            "Proposed test for part {}: assert_eq!({}(&input), {});",
+            part, function, expected
+        );
+
+        if args.update_tests {
+            match module
+                .as_deref()
+                .and_then(|source| puzzle::fill_expected(source, part, &expected))
+            {
+                Some(filled) => module = Some(filled),
+                None => println!("Could not find the `None` placeholder of test_{}", function),
+            }
+        }
+    }
+
+    if args.update_tests && module != original {
+        if let Some(module) = module {
+            fs::write(&module_path, module)
+                .map_err(|e| format!("Failed to write \"{}\": {}", module_path.display(), e))?;
+            println!("Updated tests in \"{}\"", relative(&module_path).display());
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!("Failed to process arguments: {}", e)),
+    };
+
+    if let Err(e) = run(&args) {
+        exit_with_error(e);
+    }
+
+    println!("---");
+    println!(
+        "🎄 Type `cargo test y{}::day{:02}` to check your solution against the example.",
+        args.year, args.day
+    );
+}
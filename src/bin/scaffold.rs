@@ -2,113 +2,524 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::client;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fmt::{self, Display},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+/// Imports and input parser emitted with `--nom`.
+const NOM_TEMPLATE: &str = r###"use advent_of_code::error::parse_all;
+use advent_of_code::Error;
+use nom::{
+    bytes::complete::take_till1, character::complete::newline, multi::separated_list1, IResult,
+};
+
+fn parse_line(input: &str) -> IResult<&str, &str> {
+    take_till1(|c| c == '\n')(input)
+}
+
+fn parse_input(input: &str) -> Result<Vec<&str>, Error> {
+    parse_all(input, separated_list1(newline, parse_line))
+}
+
+"###;
+
+const PARTS_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<{{ANSWER_TYPE}}> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<{{ANSWER_TYPE}}> {
     None
 }
+"###;
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// Parts that call the parser emitted with `--nom`.
+const NOM_PARTS_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<{{ANSWER_TYPE}}> {
+    let _lines = parse_input(input).ok()?;
+    None
 }
 
+pub fn part_two(input: &str) -> Option<{{ANSWER_TYPE}}> {
+    let _lines = parse_input(input).ok()?;
+    None
+}
+"###;
+
+const TESTS_TEMPLATE: &str = r###"
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", {{YEAR}}, {{DAY}});
         assert_eq!(part_one(&input), None);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
+        let input = advent_of_code::read_file("examples", {{YEAR}}, {{DAY}});
         assert_eq!(part_two(&input), None);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Tests emitted with `--rstest`: one table per part, so more examples are a single `#[case]` away.
+const RSTEST_TESTS_TEMPLATE: &str = r###"
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::example(advent_of_code::read_file("examples", {{YEAR}}, {{DAY}}), None)]
+    fn test_part_one(#[case] input: String, #[case] expected: Option<{{ANSWER_TYPE}}>) {
+        assert_eq!(part_one(&input), expected);
+    }
+
+    #[rstest]
+    #[case::example(advent_of_code::read_file("examples", {{YEAR}}, {{DAY}}), None)]
+    fn test_part_two(#[case] input: String, #[case] expected: Option<{{ANSWER_TYPE}}>) {
+        assert_eq!(part_two(&input), expected);
+    }
 }
+"###;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+struct Args {
+    day: u8,
+    /// The year folder the day is created in, `src/YYYY`.
+    year: i32,
+    /// The type of the answers, substituted for `{{ANSWER_TYPE}}`.
+    answer_type: String,
+    nom: bool,
+    rstest: bool,
+    /// A custom module template that replaces the built-in one.
+    template: Option<PathBuf>,
+    /// Only report what would be done.
+    dry_run: bool,
+    /// Overwrite an existing module file.
+    force: bool,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(client::default_year),
+        answer_type: args
+            .opt_value_from_str("--type")?
+            .unwrap_or_else(|| "u32".to_owned()),
+        nom: args.contains("--nom"),
+        rstest: args.contains("--rstest"),
+        template: args.opt_value_from_str("--template")?,
+        dry_run: args.contains("--dry-run"),
+        force: args.contains("--force"),
+        day: args.free_from_str()?,
+    })
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+/// Assembles the module template from the options, or reads the custom one passed with `--template`.
+fn module_template(args: &Args) -> Result<String, String> {
+    if let Some(path) = &args.template {
+        if args.nom || args.rstest {
+            return Err("`--nom` and `--rstest` cannot be combined with `--template`".to_owned());
         }
-    };
+        return fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read template \"{}\": {}", path.display(), e));
+    }
 
-    let day_padded = format!("{:02}", day);
+    let mut template = String::new();
+    if args.nom {
+        template.push_str(NOM_TEMPLATE);
+        template.push_str(NOM_PARTS_TEMPLATE);
+    } else {
+        template.push_str(PARTS_TEMPLATE);
+    }
+    template.push_str(if args.rstest {
+        RSTEST_TESTS_TEMPLATE
+    } else {
+        TESTS_TEMPLATE
+    });
+    Ok(template)
+}
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+/// Fills in the placeholders of a module template: `{{YEAR}}`, `{{DAY}}` and `{{ANSWER_TYPE}}`.
+///
+/// The braces keep identifiers that merely contain a placeholder name, like `DAYS`, intact.
+fn render(template: &str, year: i32, day: u8, answer_type: &str) -> String {
+    template
+        .replace("{{ANSWER_TYPE}}", answer_type)
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DAY}}", &day.to_string())
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Create,
+    /// Replace an existing file, only done for the module with `--force`.
+    Overwrite,
+    /// Leave an existing file untouched.
+    Keep,
+}
+
+/// A file the scaffold is responsible for, and what will happen to it.
+#[derive(Debug)]
+struct PlannedFile {
+    kind: &'static str,
+    path: PathBuf,
+    contents: String,
+    action: Action,
+    /// Size of the file if it already exists.
+    existing_len: Option<u64>,
+}
+
+/// Shortens paths inside the crate for reports.
+fn relative(path: &Path) -> &Path {
+    path.strip_prefix(env!("CARGO_MANIFEST_DIR"))
+        .unwrap_or(path)
+}
+
+impl PlannedFile {
+    fn verb(&self, dry_run: bool) -> &'static str {
+        match (self.action, dry_run) {
+            (Action::Create, false) => "Created",
+            (Action::Create, true) => "Would create",
+            (Action::Overwrite, false) => "Overwrote",
+            (Action::Overwrite, true) => "Would overwrite",
+            (Action::Keep, false) => "Kept existing",
+            (Action::Keep, true) => "Would keep existing",
         }
-    };
+    }
+}
+
+impl Display for PlannedFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = relative(&self.path);
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+        if self.action == Action::Create && self.contents.is_empty() {
+            write!(f, "empty ")?;
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
+        write!(f, "{} file \"{}\"", self.kind, path.display())?;
+        if let (Action::Keep, Some(len)) = (self.action, self.existing_len) {
+            write!(f, " ({} bytes)", len)?;
         }
+        Ok(())
+    }
+}
+
+/// Checks every target path before anything is written.
+///
+/// An existing module is an error unless `force` is set. Existing input and example files are
+/// always kept, as they may hold data that cannot be regenerated.
+fn plan(
+    files: Vec<(&'static str, PathBuf, String)>,
+    force: bool,
+) -> Result<Vec<PlannedFile>, String> {
+    let mut planned = vec![];
+    let mut conflicts = vec![];
+
+    for (kind, path, contents) in files {
+        let existing_len = match fs::metadata(&path) {
+            Ok(metadata) if metadata.is_dir() => {
+                conflicts.push(format!("\"{}\" is a directory", relative(&path).display()));
+                continue;
+            }
+            Ok(metadata) => Some(metadata.len()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => {
+                conflicts.push(format!(
+                    "could not inspect \"{}\": {}",
+                    relative(&path).display(),
+                    e
+                ));
+                continue;
+            }
+        };
+
+        let action = match (existing_len, kind) {
+            (None, _) => Action::Create,
+            (Some(_), "module") if force => Action::Overwrite,
+            (Some(_), "module") => {
+                conflicts.push(format!(
+                    "module file \"{}\" already exists, pass `--force` to overwrite it",
+                    relative(&path).display()
+                ));
+                continue;
+            }
+            (Some(_), _) => Action::Keep,
+        };
+
+        planned.push(PlannedFile {
+            kind,
+            path,
+            contents,
+            action,
+            existing_len,
+        });
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    if conflicts.is_empty() {
+        Ok(planned)
+    } else {
+        Err(conflicts.join("\n"))
+    }
+}
+
+/// Undoes the changes made so far: removes created files and restores overwritten ones, then
+/// removes the directories that were created for them, deepest first.
+fn rollback(done: Vec<(&PlannedFile, Option<Vec<u8>>)>, created_dirs: Vec<PathBuf>) {
+    for (file, previous) in done.into_iter().rev() {
+        let result = match previous {
+            Some(previous) => fs::write(&file.path, previous),
+            None => fs::remove_file(&file.path),
+        };
+        if let Err(e) = result {
+            eprintln!("Failed to roll back \"{}\": {}", file.path.display(), e);
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+    }
+    for dir in created_dirs.into_iter().rev() {
+        if let Err(e) = fs::remove_dir(&dir) {
+            eprintln!("Failed to roll back \"{}\": {}", dir.display(), e);
         }
     }
+}
+
+/// Writes a file, adding the directories created for it to `created_dirs`, outermost first.
+fn write_file(file: &PlannedFile, created_dirs: &mut Vec<PathBuf>) -> io::Result<()> {
+    if let Some(dir) = file.path.parent() {
+        let missing: Vec<&Path> = dir.ancestors().take_while(|dir| !dir.exists()).collect();
+        let result = fs::create_dir_all(dir);
+        // A failed `create_dir_all` may still have created some of them.
+        created_dirs.extend(
+            missing
+                .into_iter()
+                .rev()
+                .filter(|dir| dir.is_dir())
+                .map(Path::to_path_buf),
+        );
+        result?;
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true);
+    if file.action == Action::Overwrite {
+        options.truncate(true);
+    } else {
+        options.create_new(true);
+    }
+    options
+        .open(&file.path)?
+        .write_all(file.contents.as_bytes())
+}
+
+/// Writes the plan. If any file fails, the files written before it are rolled back.
+fn apply(planned: &[PlannedFile]) -> Result<(), String> {
+    let mut done = vec![];
+    let mut created_dirs = vec![];
+
+    for file in planned {
+        let previous = match file.action {
+            Action::Keep => continue,
+            Action::Create => None,
+            Action::Overwrite => match fs::read(&file.path) {
+                Ok(previous) => Some(previous),
+                Err(e) => {
+                    rollback(done, created_dirs);
+                    return Err(format!("Failed to read \"{}\": {}", file.path.display(), e));
+                }
+            },
+        };
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+        if let Err(e) = write_file(file, &mut created_dirs) {
+            rollback(done, created_dirs);
+            return Err(format!(
+                "Failed to write {} file \"{}\": {}",
+                file.kind,
+                file.path.display(),
+                e
+            ));
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+        done.push((file, previous));
+    }
+
+    Ok(())
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let module = render(
+        &module_template(args)?,
+        args.year,
+        args.day,
+        &args.answer_type,
+    );
+    let module_path = advent_of_code::year_dir(args.year).join(format!("{:02}.rs", args.day));
+
+    let planned = plan(
+        vec![
+            ("module", module_path, module),
+            (
+                "input",
+                advent_of_code::file_path("inputs", args.year, args.day),
+                String::new(),
+            ),
+            (
+                "example",
+                advent_of_code::file_path("examples", args.year, args.day),
+                String::new(),
+            ),
+        ],
+        args.force,
+    )?;
+
+    if !args.dry_run {
+        apply(&planned)?;
+    }
+
+    for file in &planned {
+        println!("{} {}", file.verb(args.dry_run), file);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
+    };
+
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        eprintln!("Nothing was scaffolded.");
+        process::exit(1);
     }
 
     println!("---");
+    if args.dry_run {
+        println!("Dry run, nothing was written.");
+        return;
+    }
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {:02} -- --year {}` to run your solution.",
+        args.day, args.year
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(nom: bool, rstest: bool) -> Args {
+        Args {
+            day: 7,
+            year: 2022,
+            answer_type: "u64".to_owned(),
+            nom,
+            rstest,
+            template: None,
+            dry_run: false,
+            force: false,
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("scaffold-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn files(dir: &Path) -> Vec<(&'static str, PathBuf, String)> {
+        vec![
+            (
+                "module",
+                dir.join("2022/07.rs"),
+                "pub fn part_one() {}\n".to_owned(),
+            ),
+            ("input", dir.join("inputs/07.txt"), String::new()),
+        ]
+    }
+
+    #[test]
+    fn test_render() {
+        let module = render(
+            &module_template(&args(false, false)).unwrap(),
+            2022,
+            7,
+            "u64",
+        );
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u64>"));
+        assert!(module.contains("advent_of_code::read_file(\"examples\", 2022, 7)"));
+        assert!(!module.contains("nom") && !module.contains("fn main"));
+
+        let module = render(
+            &module_template(&args(true, true)).unwrap(),
+            2022,
+            7,
+            "String",
+        );
+        assert!(module.contains("fn parse_input(input: &str) -> Result<Vec<&str>, Error>"));
+        assert!(module.contains("#[case] expected: Option<String>"));
+        assert!(!module.contains("{{"));
+
+        let custom = "const DAYS: [u8; 1] = [{{DAY}}]; // TODAY in {{YEAR}}\n";
+        assert_eq!(
+            render(custom, 2022, 7, "u64"),
+            "const DAYS: [u8; 1] = [7]; // TODAY in 2022\n"
+        );
+    }
+
+    #[test]
+    fn test_template_conflict() {
+        let mut args = args(true, false);
+        args.template = Some(PathBuf::from("template.rs"));
+        assert!(module_template(&args).is_err());
+    }
+
+    #[test]
+    fn test_plan() {
+        let dir = temp_dir("plan");
+        let planned = plan(files(&dir), false).unwrap();
+        assert!(planned.iter().all(|file| file.action == Action::Create));
+        apply(&planned).unwrap();
+        fs::write(dir.join("inputs/07.txt"), "1\n2\n").unwrap();
+
+        assert!(plan(files(&dir), false)
+            .unwrap_err()
+            .contains("pass `--force` to overwrite it"));
+
+        let planned = plan(files(&dir), true).unwrap();
+        assert_eq!(planned[0].action, Action::Overwrite);
+        assert_eq!(planned[1].action, Action::Keep);
+        assert_eq!(planned[1].existing_len, Some(4));
+        assert!(planned[1].to_string().ends_with("07.txt\" (4 bytes)"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply_rolls_back() {
+        let dir = temp_dir("rollback");
+        let mut files = files(&dir);
+        files.push(("example", dir.join("blocker/07.txt"), String::new()));
+        let planned = plan(files, false).unwrap();
+
+        // Turn the example folder into a regular file after planning, so that writing fails.
+        fs::write(dir.join("blocker"), "").unwrap();
+        assert!(apply(&planned).is_err());
+        assert!(!dir.join("2022/07.rs").exists());
+        assert!(!dir.join("inputs/07.txt").exists());
+        // The folders created for them are gone as well.
+        assert!(!dir.join("2022").exists());
+        assert!(!dir.join("inputs").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
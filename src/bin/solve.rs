@@ -0,0 +1,138 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::image;
+use advent_of_code::report::{self, Format};
+use advent_of_code::viz::Animator;
+use advent_of_code::{client, days, input, DayArgs};
+use std::io::ErrorKind;
+use std::process;
+
+struct Args {
+    day: u8,
+    year: Option<i32>,
+    format: Format,
+    day_args: DayArgs,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        day_args: DayArgs::parse(&mut args)?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// Prints the drawing of a part with `--render` and saves it with `--image`. This runs after the
+/// part was timed, so drawing does not count towards its runtime.
+fn draw(day: &days::Day, part: u8, input: &str, args: &DayArgs) {
+    let Some(drawing) = day.draw.and_then(|draw| draw(input, part)) else {
+        return;
+    };
+    if args.render {
+        println!("{}", drawing.text(args.color));
+    }
+    if let Some(image_path) = &args.image {
+        // Each part gets its own image, e.g. route-1.png and route-2.png for route.png.
+        let stem = image_path.file_stem().unwrap_or_default().to_string_lossy();
+        let extension = image_path.extension().unwrap_or_default().to_string_lossy();
+        let image_path = image_path.with_file_name(format!("{}-{}.{}", stem, part, extension));
+        if let Err(e) = image::save(&image_path, &drawing.pixels()) {
+            eprintln!("Failed to write {}: {}", image_path.display(), e);
+        }
+    }
+}
+
+/// Reads the input selected by `--input` or `--input-set`, and exits with a hint if it is missing.
+fn read_input(day: &days::Day, args: &DayArgs) -> String {
+    input::read_path(&args.input_path(day.year, day.day)).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        if e.kind() == ErrorKind::NotFound {
+            match args.input_set {
+                Some(_) => eprintln!(
+                    "The input sets of day {} are listed by `ls src/{}/inputs/{:02}`.",
+                    day.day, day.year, day.day
+                ),
+                None => eprintln!(
+                    "Run `cargo download {} --year {}` to fetch your puzzle input, or pass `--input <path>`.",
+                    day.day, day.year
+                ),
+            }
+        }
+        process::exit(1);
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo solve 7`");
+            process::exit(1);
+        }
+    };
+
+    // Without `--year` or `AOC_YEAR`, the most recent year that solved this day is run.
+    let day = args
+        .year
+        .or_else(client::year_from_env)
+        .or_else(|| days::latest_year(args.day))
+        .and_then(|year| days::get(year, args.day));
+    let Some(day) = day else {
+        eprintln!(
+            "There is no solution for day {}{}. Type `cargo scaffold {}` to create one.",
+            args.day,
+            args.year
+                .map_or(String::new(), |year| format!(" of {}", year)),
+            args.day
+        );
+        process::exit(1);
+    };
+
+    let drawing = args.day_args.render || args.day_args.image.is_some();
+    if drawing && day.draw.is_none() {
+        eprintln!(
+            "Day {} of {} cannot draw its solution, `--render` and `--image` are ignored.",
+            day.day, day.year
+        );
+    }
+    if args.day_args.animate && day.animate.is_none() {
+        eprintln!(
+            "Day {} of {} has no animation, `--animate` is ignored.",
+            day.day, day.year
+        );
+    }
+    // The machine-readable formats own stdout.
+    if (args.day_args.render || args.day_args.animate) && args.format != Format::Text {
+        eprintln!("`--render` and `--animate` print to the terminal, they cannot be combined with `--format`.");
+        process::exit(1);
+    }
+
+    let input = day.prepare_input(read_input(day, &args.day_args));
+
+    let mut results = vec![];
+    for (part, solver) in [(1, day.part_one), (2, day.part_two)] {
+        let result =
+            advent_of_code::solve_part(day.year, day.day, part, solver, &input, &args.day_args);
+        // The animation replays the part after it was timed, and ends before its result is shown.
+        if let (true, Some(animate)) = (args.day_args.animate, day.animate) {
+            animate(&input, part, &mut Animator::from_args(&args.day_args));
+        }
+        if args.format == Format::Text {
+            println!("{}", result);
+        }
+        if drawing {
+            draw(day, part, &input, &args.day_args);
+        }
+        results.push(result);
+    }
+
+    match args.format {
+        Format::Text => {}
+        Format::Json => println!("{}", report::to_json(&results)),
+        Format::Csv => print!("{}", report::to_csv(&results)),
+    }
+}
@@ -0,0 +1,117 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::client::{self, Client};
+use advent_of_code::submit::{self, Attempts, Verdict};
+use advent_of_code::{days, input, Status, ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<i32>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+fn run(args: &Args) -> Result<Verdict, String> {
+    if !(1..=2).contains(&args.part) {
+        return Err(format!("There is no part {}, expected 1 or 2.", args.part));
+    }
+    let year = args
+        .year
+        .or_else(client::year_from_env)
+        .or_else(|| days::latest_year(args.day))
+        .unwrap_or_else(client::default_year);
+    let day = days::get(year, args.day)
+        .ok_or_else(|| format!("There is no solution for day {} in src/{}.", args.day, year))?;
+
+    let input = input::read_path(&advent_of_code::file_path("inputs", year, args.day))
+        .map_err(|e| e.to_string())?;
+    let input = day.prepare_input(input);
+
+    let solver = if args.part == 1 {
+        day.part_one
+    } else {
+        day.part_two
+    };
+    let result = advent_of_code::run_part(year, args.day, args.part, solver, &input);
+    println!("{}", result);
+    let answer = match (&result.status, &result.answer) {
+        (Status::Solved, Some(answer)) => answer.clone(),
+        _ => return Err("There is no answer to submit.".to_owned()),
+    };
+
+    let mut answers = Answers::read(year, args.day, None).map_err(|e| e.to_string())?;
+    if let Some(recorded) = answers.get(args.part) {
+        return Err(format!(
+            "Part {} already has the recorded answer {} in src/{}/answers.",
+            args.part, recorded, year
+        ));
+    }
+
+    let attempts = Attempts::read(year, args.day).map_err(|e| e.to_string())?;
+    let client = Client::from_env().map_err(|e| e.to_string())?;
+
+    println!("---");
+    println!(
+        "Submitting {} for {} day {} part {}...",
+        answer, year, args.day, args.part
+    );
+
+    let (response, attempt) = submit::submit(
+        &client,
+        &attempts,
+        year,
+        args.day,
+        args.part,
+        &answer,
+        submit::unix_time(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    if let Err(e) = Attempts::append(year, args.day, &attempt) {
+        eprintln!("Failed to log the attempt: {}", e);
+    }
+
+    println!(
+        "{}{}:{} {}",
+        ANSI_BOLD, response.verdict, ANSI_RESET, response.message
+    );
+
+    if response.verdict == Verdict::Correct {
+        answers.record(&result);
+        if let Err(e) = answers.write(year, args.day, None) {
+            eprintln!("Failed to record the answer: {}", e);
+        }
+    }
+    Ok(response.verdict)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day and a part. example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    match run(&args) {
+        Ok(Verdict::Correct) => {}
+        Ok(_) => process::exit(1),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
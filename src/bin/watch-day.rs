@@ -0,0 +1,406 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::{client, days, report, DayArgs, ANSI_BOLD, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Number of compiler error lines shown when the build fails.
+const BUILD_ERROR_LINES: usize = 5;
+
+struct Args {
+    day: u8,
+    year: i32,
+    /// How often the watched files are checked for changes.
+    interval: Duration,
+    /// Run the solver against this named input instead of the default one.
+    input_set: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year: Option<i32> = args.opt_value_from_str(["-y", "--year"])?;
+    let interval = args.opt_value_from_str("--interval")?.unwrap_or(500);
+    let input_set = args.opt_value_from_str("--input-set")?;
+    let day = args.free_from_str()?;
+
+    Ok(Args {
+        day,
+        year: year
+            .or_else(client::year_from_env)
+            .or_else(|| days::latest_year(day))
+            .unwrap_or_else(client::default_year),
+        interval: Duration::from_millis(interval),
+        input_set,
+    })
+}
+
+/// Modification times of the watched files, `None` for files that do not exist (yet).
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The module, example, input and recorded answers of the day.
+fn watched_files(args: &Args) -> Vec<PathBuf> {
+    let day_args = DayArgs {
+        input_set: args.input_set.clone(),
+        ..DayArgs::default()
+    };
+
+    vec![
+        advent_of_code::year_dir(args.year).join(format!("{:02}.rs", args.day)),
+        advent_of_code::file_path("examples", args.year, args.day),
+        day_args.input_path(args.year, args.day),
+        Answers::path(args.year, args.day, args.input_set.as_deref()),
+    ]
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|metadata| metadata.modified());
+            (path.clone(), modified.ok())
+        })
+        .collect()
+}
+
+/// Files that were created, modified or removed between two snapshots.
+fn changed<'a>(before: &Snapshot, after: &'a Snapshot) -> Vec<&'a Path> {
+    after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path.as_path())
+        .collect()
+}
+
+fn relative(path: &Path) -> &Path {
+    path.strip_prefix(env!("CARGO_MANIFEST_DIR"))
+        .unwrap_or(path)
+}
+
+fn cargo(args: &[&str]) -> io::Result<Output> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    Command::new(cargo)
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum TestOutcome {
+    /// The crate did not compile, with the first compiler errors.
+    BuildFailed(Vec<String>),
+    Passed(usize),
+    Failed {
+        passed: usize,
+        failed: Vec<String>,
+    },
+}
+
+/// Reads the outcome of `cargo test` from its output.
+fn parse_test_output(stdout: &str, stderr: &str) -> TestOutcome {
+    let mut passed = None;
+    let mut failed = vec![];
+
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.strip_suffix(" ... FAILED"))
+        {
+            failed.push(name.to_owned());
+        } else if let Some(summary) = line.strip_prefix("test result: ") {
+            let count = summary.split("; ").find_map(|field| {
+                field
+                    .strip_suffix(" passed")?
+                    .rsplit(' ')
+                    .next()?
+                    .parse::<usize>()
+                    .ok()
+            });
+            passed = Some(passed.unwrap_or(0) + count.unwrap_or(0));
+        }
+    }
+
+    match passed {
+        None => TestOutcome::BuildFailed(
+            stderr
+                .lines()
+                .filter(|line| line.starts_with("error"))
+                .take(BUILD_ERROR_LINES)
+                .map(str::to_owned)
+                .collect(),
+        ),
+        Some(passed) if failed.is_empty() => TestOutcome::Passed(passed),
+        Some(passed) => TestOutcome::Failed { passed, failed },
+    }
+}
+
+/// One part as reported by `cargo solve --format csv`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PartRun {
+    part: u8,
+    answer: String,
+    status: String,
+}
+
+/// Parses the records after the CSV header, skipping anything the solvers printed before it.
+fn parse_solve_output(output: &str) -> Vec<PartRun> {
+    let Some(start) = output.find(report::CSV_HEADER) else {
+        return vec![];
+    };
+    let mut records = report::csv_records(&output[start..]).into_iter();
+    let header = records
+        .next()
+        .map(report::parse_csv_record)
+        .unwrap_or_default();
+    let column = |name: &str| header.iter().position(|field| field == name);
+    let (Some(part), Some(answer), Some(status)) =
+        (column("part"), column("answer"), column("status"))
+    else {
+        return vec![];
+    };
+
+    records
+        .map(report::parse_csv_record)
+        .filter_map(|record| {
+            Some(PartRun {
+                part: record.get(part)?.parse().ok()?,
+                answer: record.get(answer)?.clone(),
+                status: record.get(status)?.clone(),
+            })
+        })
+        .collect()
+}
+
+/// A compact line for one part, with the change against the previous run.
+fn describe(run: &PartRun, previous: Option<&PartRun>, expected: Option<&str>) -> String {
+    let mut line = format!("part {}  ", run.part);
+    match run.status.as_str() {
+        "unsolved" => line.push_str("not solved"),
+        "error" => line.push_str("error"),
+        "no_input" => line.push_str("no input"),
+        _ => line.push_str(&run.answer),
+    }
+
+    match previous {
+        Some(previous) if previous.answer != run.answer && !previous.answer.is_empty() => {
+            line.push_str(&format!(" (was {})", previous.answer))
+        }
+        Some(previous) if previous.answer == run.answer && !run.answer.is_empty() => {
+            line.push_str(" (unchanged)")
+        }
+        _ => {}
+    }
+
+    match (run.status.as_str(), expected) {
+        ("mismatch", Some(expected)) => line.push_str(&format!(" ✘ expected {}", expected)),
+        ("solved", Some(_)) => line.push_str(" ✔"),
+        _ => {}
+    }
+    line
+}
+
+/// Runs the day's tests and then the solver, and prints a summary of both.
+///
+/// Returns the parts of this run, or the previous ones if the solver could not be run.
+fn run_cycle(args: &Args, previous: Vec<PartRun>) -> Vec<PartRun> {
+    let filter = format!("y{}::day{:02}::", args.year, args.day);
+    let output = match cargo(&["test", "--lib", "--", &filter]) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let outcome = parse_test_output(
+        &String::from_utf8_lossy(&output.stdout),
+        &String::from_utf8_lossy(&output.stderr),
+    );
+    match &outcome {
+        TestOutcome::BuildFailed(errors) => {
+            println!("{}build ✘{}", ANSI_BOLD, ANSI_RESET);
+            for error in errors {
+                println!("  {}", error);
+            }
+            return previous;
+        }
+        TestOutcome::Passed(0) => println!("{}tests{}   none found", ANSI_BOLD, ANSI_RESET),
+        TestOutcome::Passed(passed) => {
+            println!("{}tests ✔{} {} passed", ANSI_BOLD, ANSI_RESET, passed)
+        }
+        TestOutcome::Failed { passed, failed } => println!(
+            "{}tests ✘{} {} passed, {} failed: {}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            passed,
+            failed.len(),
+            failed.join(", ")
+        ),
+    }
+
+    let day = format!("{:02}", args.day);
+    let year = args.year.to_string();
+    let mut solve_args = vec![
+        "run", "--quiet", "--bin", "solve", "--", &day, "--year", &year, "--format", "csv",
+    ];
+    if let Some(input_set) = &args.input_set {
+        solve_args.extend(["--input-set", input_set]);
+    }
+
+    let output = match cargo(&solve_args) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {}", e);
+            process::exit(1);
+        }
+    };
+    let runs = parse_solve_output(&String::from_utf8_lossy(&output.stdout));
+    if !output.status.success() || runs.is_empty() {
+        println!("{}solve ✘{}", ANSI_BOLD, ANSI_RESET);
+        for line in String::from_utf8_lossy(&output.stderr)
+            .lines()
+            .rev()
+            .take(2)
+        {
+            println!("  {}", line);
+        }
+        return previous;
+    }
+
+    let answers = Answers::read(args.year, args.day, args.input_set.as_deref()).unwrap_or_default();
+    for run in &runs {
+        let previous = previous.iter().find(|previous| previous.part == run.part);
+        println!("{}", describe(run, previous, answers.get(run.part)));
+    }
+    runs
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let files = watched_files(&args);
+    println!(
+        "Watching {} day {}, press Ctrl-C to stop.",
+        args.year, args.day
+    );
+    for file in &files {
+        println!("  {}", relative(file).display());
+    }
+    println!("---");
+
+    let mut seen = snapshot(&files);
+    let mut runs = run_cycle(&args, vec![]);
+
+    loop {
+        thread::sleep(args.interval);
+        if snapshot(&files) == seen {
+            continue;
+        }
+
+        // Editors often write a file in several steps, give them a moment to finish.
+        thread::sleep(Duration::from_millis(50));
+        let current = snapshot(&files);
+        println!("---");
+        for path in changed(&seen, &current) {
+            println!("changed {}", relative(path).display());
+        }
+        seen = current;
+        runs = run_cycle(&args, runs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changed() {
+        let time = SystemTime::UNIX_EPOCH;
+        let before = Snapshot::from([
+            (PathBuf::from("01.rs"), Some(time)),
+            (PathBuf::from("01.txt"), None),
+        ]);
+        let after = Snapshot::from([
+            (PathBuf::from("01.rs"), Some(time + Duration::from_secs(1))),
+            (PathBuf::from("01.txt"), None),
+        ]);
+
+        assert_eq!(changed(&before, &after), vec![Path::new("01.rs")]);
+        assert!(changed(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_parse_test_output() {
+        let stdout = "running 2 tests\ntest days::y2022::day01::tests::test_part_one ... ok\ntest days::y2022::day01::tests::test_part_two ... FAILED\n\ntest result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 126 filtered out; finished in 0.00s\n";
+        assert_eq!(
+            parse_test_output(stdout, ""),
+            TestOutcome::Failed {
+                passed: 1,
+                failed: vec!["days::y2022::day01::tests::test_part_two".to_owned()]
+            }
+        );
+
+        let stdout = "test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 126 filtered out; finished in 0.00s\n";
+        assert_eq!(parse_test_output(stdout, ""), TestOutcome::Passed(2));
+
+        let stderr = "   Compiling advent_of_code v0.8.0\nerror[E0425]: cannot find value `x` in this scope\n";
+        assert_eq!(
+            parse_test_output("", stderr),
+            TestOutcome::BuildFailed(vec![
+                "error[E0425]: cannot find value `x` in this scope".to_owned()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_solve_output() {
+        let output = concat!(
+            "debug output, \"unbalanced\n",
+            "day,part,answer,elapsed_ns,status,input_set,year\n",
+            "10,1,13140,10,solved,,2022\n",
+            "10,2,\"##..\n###.\",10,solved,,2022\n",
+        );
+        let runs = parse_solve_output(output);
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[1].answer, "##..\n###.");
+        assert_eq!(parse_solve_output("not solved\n"), vec![]);
+    }
+
+    #[test]
+    fn test_describe() {
+        let runs = parse_solve_output(
+            "day,part,answer,elapsed_ns,status,input_set,year\n1,1,24000,10,solved,,2022\n1,2,45001,10,mismatch,,2022\n",
+        );
+        assert_eq!(runs.len(), 2);
+
+        assert_eq!(describe(&runs[0], None, None), "part 1  24000");
+        assert_eq!(
+            describe(&runs[0], Some(&runs[0]), Some("24000")),
+            "part 1  24000 (unchanged) ✔"
+        );
+
+        let previous = PartRun {
+            answer: "45000".to_owned(),
+            ..runs[1].clone()
+        };
+        assert_eq!(
+            describe(&runs[1], Some(&previous), Some("45000")),
+            "part 2  45001 (was 45000) ✘ expected 45000"
+        );
+    }
+}
@@ -0,0 +1,448 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Environment variable holding the session cookie of an adventofcode.com login.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable that points the client at a different server, e.g. a local stub.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Environment variable that selects the puzzle year when `--year` is not passed.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, so the site operators know where the traffic comes from.
+pub const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (Rust Advent of Code template; ",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session cookie in `AOC_SESSION` or any of the session files.
+    NoSession,
+    /// The server answered with an error status.
+    Status { code: u16, body: String },
+    /// The request did not reach the server or the response could not be read.
+    Transport(String),
+    /// The cache could not be read or written.
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "no session cookie found. Set `{}` or save it to one of: {}",
+                SESSION_VAR,
+                session_files()
+                    .iter()
+                    .map(|path| format!("\"{}\"", path.display()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            ClientError::Status { code, body } => {
+                write!(f, "server responded with status {}", code)?;
+                match body.trim() {
+                    "" => Ok(()),
+                    body => write!(f, ": {}", body),
+                }
+            }
+            ClientError::Transport(message) => write!(f, "request failed: {}", message),
+            ClientError::Io(e) => write!(f, "cache error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Files the session cookie is read from when `AOC_SESSION` is not set. The first two are the
+/// locations `aoc-cli` uses, so an existing setup keeps working.
+pub fn session_files() -> Vec<PathBuf> {
+    let mut files = vec![];
+    if let Some(home) = env::var_os("HOME") {
+        files.push(Path::new(&home).join(".adventofcode.session"));
+    }
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(config) => files.push(Path::new(&config).join("adventofcode.session")),
+        None => {
+            if let Some(home) = env::var_os("HOME") {
+                files.push(Path::new(&home).join(".config/adventofcode.session"));
+            }
+        }
+    }
+    files
+}
+
+/// Reads the session cookie from `AOC_SESSION`, or from the first session file that exists.
+pub fn read_session() -> Result<String, ClientError> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_owned());
+        }
+    }
+
+    for path in session_files() {
+        match fs::read_to_string(&path) {
+            Ok(session) if !session.trim().is_empty() => return Ok(session.trim().to_owned()),
+            Ok(_) => {}
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Err(ClientError::NoSession)
+}
+
+/// The year of the most recent event: the current year from December on, the previous one before.
+pub fn current_event_year() -> i32 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs() as i64
+        / 86_400;
+    let (year, month) = civil_from_days(days);
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Year and month of a day count since 1970-01-01 (proleptic Gregorian calendar).
+fn civil_from_days(days: i64) -> (i32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u32)
+}
+
+/// The puzzle year set in `AOC_YEAR`, if any.
+pub fn year_from_env() -> Option<i32> {
+    env::var(YEAR_VAR).ok().and_then(|year| year.parse().ok())
+}
+
+/// The puzzle year from `AOC_YEAR`, falling back to the most recent event.
+pub fn default_year() -> i32 {
+    year_from_env().unwrap_or_else(current_event_year)
+}
+
+/// A minimal adventofcode.com client that authenticates with a session cookie.
+///
+/// Inputs never change once published, so they are cached below `target/aoc-cache` and fetched
+/// only once per year and day. Inputs and puzzle texts differ by account, so every session gets a
+/// folder of its own.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            session: session.into(),
+            cache_dir: Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("target")
+                .join("aoc-cache"),
+        }
+    }
+
+    /// Creates a client from the configured session, honouring `AOC_BASE_URL`.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let client = Self::new(read_session()?);
+        Ok(match env::var(BASE_URL_VAR) {
+            Ok(base_url) => client.with_base_url(base_url),
+            Err(_) => client,
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| ClientError::Transport(e.to_string())),
+            Err(ureq::Error::Status(code, response)) => Err(ClientError::Status {
+                code,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(e)) => Err(ClientError::Transport(e.to_string())),
+        }
+    }
+
+    /// Sends an authenticated GET request for `path`, e.g. `/2022/day/1`.
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        Self::read_response(
+            self.agent
+                .get(&self.url(path))
+                .set("Cookie", &self.cookie())
+                .call(),
+        )
+    }
+
+    /// Sends an authenticated form POST to `path`.
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        Self::read_response(
+            self.agent
+                .post(&self.url(path))
+                .set("Cookie", &self.cookie())
+                .send_form(form),
+        )
+    }
+
+    /// Where the response for `path` is cached. The file is only written after a successful request.
+    pub fn cache_path(&self, path: &str) -> PathBuf {
+        let session_dir = self
+            .cache_dir
+            .join(format!("{:016x}", fnv1a(&self.session)));
+        path.split('/')
+            .filter(|segment| !segment.is_empty() && *segment != "..")
+            .fold(session_dir, |dir, segment| dir.join(segment))
+    }
+
+    /// Like `get`, but serves repeated requests from the cache.
+    pub fn get_cached(&self, path: &str) -> Result<String, ClientError> {
+        let cache_path = self.cache_path(path);
+        match fs::read_to_string(&cache_path) {
+            Ok(cached) => return Ok(cached),
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let body = self.get(path)?;
+        write_atomic(&cache_path, &body)?;
+        Ok(body)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn fetch_input(&self, year: i32, day: u8) -> Result<String, ClientError> {
+        self.get_cached(&format!("/{}/day/{}/input", year, day))
+    }
+}
+
+/// A hash that stays the same across builds, so the cache folder of a session can be found again
+/// without storing the cookie itself.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Writes `contents` to a process-specific temporary file next to `path` and renames it into
+/// place, so concurrent writers never see or produce a half-written file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".tmp-{}", std::process::id()));
+    let tmp = PathBuf::from(tmp);
+
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+/// A one-shot HTTP server on localhost that records requests and replays canned responses.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// A request as received by the stub: request line, lowercase headers and body.
+    #[derive(Debug)]
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Serves one `(status, body)` response per connection and returns the base URL together with
+    /// a channel of the received requests.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = vec![];
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    match header.trim_end().split_once(':') {
+                        Some((key, value)) => {
+                            headers.push((key.to_lowercase(), value.trim().to_owned()))
+                        }
+                        None => break,
+                    }
+                }
+                let len = headers
+                    .iter()
+                    .find(|(key, _)| key == "content-length")
+                    .map_or(0, |(_, value)| value.parse().unwrap());
+                let mut request_body = vec![0; len];
+                reader.read_exact(&mut request_body).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+
+                let _ = sender.send(Request {
+                    line: line.trim_end().to_owned(),
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+            }
+        });
+
+        (base_url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, requests) = stub::serve(vec![(200, "1\n2\n3\n")]);
+        let cache_dir = cache_dir("fetch");
+        let client = Client::new("abc123")
+            .with_base_url(base_url)
+            .with_cache_dir(&cache_dir);
+
+        assert_eq!(client.fetch_input(2022, 1).unwrap(), "1\n2\n3\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2022/day/1/input HTTP/1.1");
+        assert_eq!(request.header("cookie"), Some("session=abc123"));
+        assert_eq!(request.header("user-agent"), Some(USER_AGENT));
+
+        // The stub only answers once, so this must come from the cache.
+        assert_eq!(client.fetch_input(2022, 1).unwrap(), "1\n2\n3\n");
+        let cache_path = client.cache_path("/2022/day/1/input");
+        assert!(cache_path.starts_with(&cache_dir) && cache_path.exists());
+
+        // Another session must not be served the cached input of the first one.
+        let other = Client::new("def456").with_cache_dir(&cache_dir);
+        assert_ne!(other.cache_path("/2022/day/1/input"), cache_path);
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_error_status() {
+        let (base_url, _requests) = stub::serve(vec![(400, "Puzzle inputs differ by user.")]);
+        let cache_dir = cache_dir("status");
+        let client = Client::new("expired")
+            .with_base_url(base_url)
+            .with_cache_dir(&cache_dir);
+
+        let e = client.fetch_input(2022, 2).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "server responded with status 400: Puzzle inputs differ by user."
+        );
+        assert!(!cache_dir.exists());
+    }
+
+    #[test]
+    fn test_post_form() {
+        let (base_url, requests) = stub::serve(vec![(200, "ok")]);
+        let client = Client::new("abc123").with_base_url(base_url);
+
+        assert_eq!(
+            client
+                .post_form("/2022/day/1/answer", &[("level", "1"), ("answer", "24000")])
+                .unwrap(),
+            "ok"
+        );
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2022/day/1/answer HTTP/1.1");
+        assert_eq!(request.body, "level=1&answer=24000");
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1));
+        assert_eq!(civil_from_days(19_327), (2022, 12));
+        assert_eq!(civil_from_days(19_358), (2023, 1));
+    }
+}
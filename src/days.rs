@@ -0,0 +1,113 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+use crate::image::Drawing;
+use crate::viz::Animator;
+use crate::{Answer, IntoAnswer};
+
+/// Draws the solution of a part for an input, `None` if there is nothing to draw.
+pub type Draw = fn(&str, u8) -> Option<Box<dyn Drawing>>;
+
+/// Runs a part again for an input, showing every step of its simulation with the animator.
+pub type Animate = fn(&str, u8, &mut Animator);
+
+/// A registered solution: the `part_one` / `part_two` functions of `src/YYYY/NN.rs`.
+///
+/// Answers are converted to strings so that every day fits the same function pointer type.
+pub struct Day {
+    pub year: i32,
+    pub day: u8,
+    pub part_one: fn(&str) -> Answer,
+    pub part_two: fn(&str) -> Answer,
+    /// The day's `NORMALIZE_INPUT`, see `defaults`.
+    pub normalize_input: bool,
+    /// The day's `DRAW`, see `defaults`.
+    pub draw: Option<Draw>,
+    /// The day's `ANIMATE`, see `defaults`.
+    pub animate: Option<Animate>,
+}
+
+impl Day {
+    /// Prepares an input file the way the day expects it.
+    pub fn prepare_input(&self, input: String) -> String {
+        if self.normalize_input {
+            crate::input::normalize(&input)
+        } else {
+            input
+        }
+    }
+}
+
+/// The constants a day gets unless its module declares its own, e.g.
+/// `pub const NORMALIZE_INPUT: bool = false;`.
+pub mod defaults {
+    /// Whether the input and examples are normalized before they reach the solver, see
+    /// `input::normalize`.
+    pub const NORMALIZE_INPUT: bool = true;
+
+    /// How `--render` and `--image` draw the solution of a part, `None` for days without a
+    /// picture.
+    pub const DRAW: Option<super::Draw> = None;
+
+    /// How `--animate` shows a part step by step, `None` for days without a simulation.
+    pub const ANIMATE: Option<super::Animate> = None;
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $year_module:ident, $module:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            normalize_input: $year_module::$module::NORMALIZE_INPUT,
+            draw: $year_module::$module::DRAW,
+            animate: $year_module::$module::ANIMATE,
+            part_one: |input| Answer($year_module::$module::part_one(input).into_answer()),
+            part_two: |input| Answer($year_module::$module::part_two(input).into_answer()),
+        }
+    };
+}
+
+// `yYYYY::dayNN` modules for every `src/YYYY/NN.rs` and the `DAYS` table, generated by `build.rs`.
+// The table is sorted by year, then day.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+pub fn get(year: i32, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+/// The years that have at least one solution, in ascending order.
+pub fn years() -> Vec<i32> {
+    let mut years: Vec<i32> = DAYS.iter().map(|d| d.year).collect();
+    years.dedup();
+    years
+}
+
+/// The most recent year that has a solution for `day`.
+pub fn latest_year(day: u8) -> Option<i32> {
+    DAYS.iter().rev().find(|d| d.day == day).map(|d| d.year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(DAYS
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+        assert!(years().contains(&2022));
+        assert_eq!(latest_year(16), None);
+
+        let day = get(2022, 1).unwrap();
+        assert!(day.normalize_input);
+        let input = crate::read_file("examples", 2022, 1);
+        assert_eq!((day.part_one)(&input).0, Ok(Some("24000".to_owned())));
+        assert_eq!((day.part_two)(&input).0, Ok(Some("45000".to_owned())));
+
+        assert!(get(2022, 16).is_none());
+        assert!(get(2015, 1).is_none());
+    }
+}
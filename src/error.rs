@@ -0,0 +1,152 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+
+use nom::IResult;
+
+/// Errors a solver can return instead of panicking on unexpected input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input does not have the expected format. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input was parsed, but does not describe a state the puzzle allows.
+    InvalidState(String),
+}
+
+impl Error {
+    /// Creates a parse error pointing at the start of `at`, which must be a slice of `input`.
+    pub fn parse(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        Error::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_state(message: impl Into<String>) -> Self {
+        Error::InvalidState(message.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: {}",
+                line, column, message
+            ),
+            Error::InvalidState(message) => write!(f, "invalid puzzle state: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Runs a nom parser over the whole input. Only whitespace may be left over.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, Error> {
+    match parser(input) {
+        Ok((remaining, output)) => {
+            let remaining = remaining.trim_start();
+            if remaining.is_empty() {
+                Ok(output)
+            } else {
+                Err(Error::parse(input, remaining, "unexpected input"))
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(Error::parse(
+            input,
+            e.input,
+            format!(
+                "unexpected input, expected {}",
+                e.code.description().to_lowercase()
+            ),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(Error::parse(
+            input,
+            &input[input.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{
+        bytes::complete::tag, character::complete::newline, multi::separated_list1,
+        sequence::separated_pair,
+    };
+
+    fn pairs(input: &str) -> IResult<&str, Vec<(u32, u32)>> {
+        separated_list1(
+            newline,
+            separated_pair(
+                nom::character::complete::u32,
+                tag(","),
+                nom::character::complete::u32,
+            ),
+        )(input)
+    }
+
+    #[test]
+    fn test_parse_position() {
+        let input = "1,2\n3,4\nfoo";
+        let foo = input.lines().last().unwrap();
+        assert_eq!(
+            Error::parse(input, foo, "oops"),
+            Error::Parse {
+                line: 3,
+                column: 1,
+                message: "oops".to_owned()
+            }
+        );
+        assert_eq!(
+            Error::parse(input, &input[5..], "oops").to_string(),
+            "parse error at line 2, column 2: oops"
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(parse_all("1,2\n3,4\n", pairs), Ok(vec![(1, 2), (3, 4)]));
+        assert_eq!(
+            parse_all("1,2\n3,4\n5;6\n", pairs),
+            Err(Error::Parse {
+                line: 3,
+                column: 1,
+                message: "unexpected input".to_owned()
+            })
+        );
+        assert_eq!(
+            parse_all("x,2", pairs),
+            Err(Error::Parse {
+                line: 1,
+                column: 1,
+                message: "unexpected input, expected digit".to_owned()
+            })
+        );
+    }
+}
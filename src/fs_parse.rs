@@ -26,7 +26,7 @@ pub enum LsEntry<'a> {
     Dir(&'a str),
 }
 
-fn dir(input: &str) -> IResult<&str, LsEntry> {
+fn dir(input: &str) -> IResult<&str, LsEntry<'_>> {
     let (input, dir_name) = preceded(tag("dir "), alpha1)(input)?;
     Ok((input, LsEntry::Dir(dir_name)))
 }
@@ -35,14 +35,14 @@ fn filename(input: &str) -> IResult<&str, &str> {
     is_a("qwertyuiopasdfghjklzxcvbnm.")(input)
 }
 
-fn file(input: &str) -> IResult<&str, LsEntry> {
+fn file(input: &str) -> IResult<&str, LsEntry<'_>> {
     let (input, (size, name)) =
         separated_pair(nom::character::complete::u32, tag(" "), filename)(input)?;
 
     Ok((input, LsEntry::File { name, size }))
 }
 
-fn ls(input: &str) -> IResult<&str, Operation> {
+fn ls(input: &str) -> IResult<&str, Operation<'_>> {
     let (input, _) = tag("$ ls")(input)?;
     let (input, _) = newline(input)?;
     let (input, ls_entries) = separated_list1(newline, alt((dir, file)))(input)?;
@@ -50,7 +50,7 @@ fn ls(input: &str) -> IResult<&str, Operation> {
     Ok((input, Operation::Ls(ls_entries)))
 }
 
-fn cd(input: &str) -> IResult<&str, Operation> {
+fn cd(input: &str) -> IResult<&str, Operation<'_>> {
     let (input, _) = tag("$ cd ")(input)?;
     let (input, cd) = alt((tag(".."), tag("/"), alpha1))(input)?;
     let op = match cd {
@@ -61,7 +61,7 @@ fn cd(input: &str) -> IResult<&str, Operation> {
     Ok((input, op))
 }
 
-pub fn parse_operations(input: &str) -> IResult<&str, Vec<Operation>> {
+pub fn parse_operations(input: &str) -> IResult<&str, Vec<Operation<'_>>> {
     let (input, operations) = separated_list1(newline, alt((ls, cd)))(input)?;
     Ok((input, operations))
 }
@@ -0,0 +1,359 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::point::Point;
+use crate::Error;
+
+/// A rectangular grid stored row by row, indexed by `Point` with `y` growing downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`. A grid without columns has no rows either.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let height = if width == 0 { 0 } else { height };
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells in row order. `None` if they do not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a character map with one row per line, converting every character with `cell`.
+    ///
+    /// Fails at the first character `cell` rejects, and at rows that are shorter or longer than
+    /// the first one.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, Error> {
+        let mut width = None;
+        let mut cells = vec![];
+
+        for line in input.lines() {
+            let mut row_width = 0;
+            for (offset, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::parse(
+                        input,
+                        &line[offset..],
+                        format!("unexpected character '{}'", c),
+                    )
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(Error::parse(
+                        input,
+                        line,
+                        format!("expected a row of {} cells, found {}", width, row_width),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        let width = width.filter(|width| *width > 0);
+        let width = width.ok_or_else(|| Error::parse(input, input, "the grid is empty"))?;
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn point(&self, offset: usize) -> Point {
+        Point {
+            x: (offset % self.width) as i32,
+            y: (offset / self.width) as i32,
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// All points of the grid in row order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|offset| self.point(offset))
+    }
+
+    /// All cells with their points, in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(offset, cell)| {
+            let point = Point {
+                x: (offset % width) as i32,
+                y: (offset / width) as i32,
+            };
+            (point, cell)
+        })
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(offset, cell)| {
+                let point = Point {
+                    x: (offset % width) as i32,
+                    y: (offset / width) as i32,
+                };
+                (point, cell)
+            })
+    }
+
+    /// The first point in row order whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|offset| self.point(offset))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x` from top to bottom, empty if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// The orthogonal neighbours of `point` that lie inside the grid, clockwise from the one above.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors4()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` that lie inside the grid, clockwise from
+    /// the one above.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors8()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The cells seen when walking from `from` in steps of `step` until leaving the grid.
+    /// `from` itself is not included.
+    pub fn ray(&self, from: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        let mut current = from;
+        std::iter::from_fn(move || {
+            if step == Point::default() {
+                return None;
+            }
+            current += step;
+            self.get(current).map(|cell| (current, cell))
+        })
+    }
+
+    /// Converts every cell with `f`, keeping the shape of the grid.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.offset(point) {
+            Some(offset) => &self.cells[offset],
+            None => panic!(
+                "point ({}, {}) is outside of the {}x{} grid",
+                point.x, point.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.offset(point) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!(
+                "point ({}, {}) is outside of the {}x{} grid",
+                point.x, point.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Prints the grid as a character map, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[point(2, 1)], 6);
+        assert_eq!(grid.get(point(3, 0)), None);
+        assert_eq!(grid.get(point(-1, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert_eq!(
+            Grid::parse("12\n3x\n", |c| c.to_digit(10)),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                message: "unexpected character 'x'".to_owned()
+            })
+        );
+        assert_eq!(
+            Grid::parse("12\n345\n", |c| c.to_digit(10)),
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                message: "expected a row of 2 cells, found 3".to_owned()
+            })
+        );
+        assert!(Grid::parse("", Some).is_err());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456\n789\n");
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5, 8]);
+        assert_eq!(
+            grid.column(2).rev().copied().collect::<Vec<_>>(),
+            vec![9, 6, 3]
+        );
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.position(|cell| *cell == 8), Some(point(1, 2)));
+    }
+
+    #[test]
+    fn test_new_without_columns() {
+        let grid = Grid::new(0, 3, '.');
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.row(0), None);
+        assert_eq!(grid.points().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, '.');
+        assert_eq!(
+            grid.neighbors4(point(1, 1)).collect::<Vec<_>>(),
+            vec![point(1, 0), point(2, 1), point(1, 2), point(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors4(point(0, 0)).collect::<Vec<_>>(),
+            vec![point(1, 0), point(0, 1)]
+        );
+        assert_eq!(grid.neighbors8(point(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8(point(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits("123\n456\n789\n");
+        let cells = |from, step| {
+            grid.ray(from, step)
+                .map(|(_, cell)| *cell)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(cells(point(0, 0), point(1, 1)), vec![5, 9]);
+        assert_eq!(cells(point(2, 1), point(-1, 0)), vec![5, 4]);
+        assert_eq!(cells(point(1, 0), point(0, -1)), vec![]);
+        assert_eq!(cells(point(1, 1), point(0, 0)), vec![]);
+    }
+
+    #[test]
+    fn test_map_and_index_mut() {
+        let mut grid = digits("12\n34\n").map(|cell| cell * 10);
+        grid[point(0, 1)] += 1;
+        *grid.get_mut(point(1, 0)).unwrap() = 0;
+        assert_eq!(
+            grid.iter().map(|(_, cell)| *cell).collect::<Vec<_>>(),
+            vec![10, 0, 31, 40]
+        );
+        assert_eq!(Grid::from_vec(2, vec![10, 0, 31, 40]), Some(grid));
+        assert_eq!(Grid::from_vec(3, vec![1, 2]), None);
+    }
+}
@@ -0,0 +1,185 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+use crate::grid::Grid;
+
+/// A picture of a solution, e.g. a route on a map. `cargo solve` prints it with `--render` and
+/// saves it with `--image`, after the part was timed.
+pub trait Drawing {
+    /// The picture as text, with ANSI colours if `color` is set.
+    fn text(&self, color: bool) -> String;
+
+    /// The picture as pixels.
+    fn pixels(&self) -> Grid<Rgb>;
+}
+
+#[derive(Default, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// The ANSI escape code that sets this as the background colour of a true-colour terminal.
+    pub fn ansi_background(&self) -> String {
+        format!("\x1b[48;2;{};{};{}m", self.0, self.1, self.2)
+    }
+}
+
+/// Enlarges every pixel to a square of `scale` by `scale` pixels.
+pub fn scale(pixels: &Grid<Rgb>, scale: usize) -> Grid<Rgb> {
+    let mut scaled = Grid::new(
+        pixels.width() * scale,
+        pixels.height() * scale,
+        Rgb::default(),
+    );
+    for (point, pixel) in scaled.iter_mut() {
+        let x = point.x as usize / scale;
+        let y = point.y as usize / scale;
+        *pixel = pixels.row(y).unwrap()[x];
+    }
+    scaled
+}
+
+/// Encodes the pixels as a binary PPM image.
+pub fn ppm(pixels: &Grid<Rgb>) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", pixels.width(), pixels.height()).into_bytes();
+    for (_, Rgb(r, g, b)) in pixels.iter() {
+        image.extend([*r, *g, *b]);
+    }
+    image
+}
+
+/// Encodes the pixels as a PNG image. The image data is stored without compression, which keeps
+/// the encoder small and is fine for the sizes of puzzle maps.
+pub fn png(pixels: &Grid<Rgb>) -> Vec<u8> {
+    let mut header = vec![];
+    header.extend((pixels.width() as u32).to_be_bytes());
+    header.extend((pixels.height() as u32).to_be_bytes());
+    // 8 bits per channel, RGB, default compression, filtering and no interlacing.
+    header.extend([8, 2, 0, 0, 0]);
+
+    // Every row starts with its filter type, 0 for none.
+    let mut data = vec![];
+    for row in pixels.rows() {
+        data.push(0);
+        for Rgb(r, g, b) in row {
+            data.extend([*r, *g, *b]);
+        }
+    }
+
+    let mut image = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut image, b"IHDR", &header);
+    chunk(&mut image, b"IDAT", &zlib_stored(&data));
+    chunk(&mut image, b"IEND", &[]);
+    image
+}
+
+/// Writes the pixels as a PNG or PPM image, depending on the extension of `path`.
+pub fn save(path: &Path, pixels: &Grid<Rgb>) -> io::Result<()> {
+    let extension = path.extension().and_then(|extension| extension.to_str());
+    let image = match extension.map(|extension| extension.to_ascii_lowercase()) {
+        Some(extension) if extension == "png" => png(pixels),
+        Some(extension) if extension == "ppm" => ppm(pixels),
+        _ => {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "cannot write {}, expected a .png or .ppm file",
+                    path.display()
+                ),
+            ))
+        }
+    };
+    fs::write(path, image)
+}
+
+fn chunk(image: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    image.extend((data.len() as u32).to_be_bytes());
+    let start = image.len();
+    image.extend(kind);
+    image.extend(data);
+    let crc = crc32(&image[start..]);
+    image.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        stream.push(last as u8);
+        stream.extend((block.len() as u16).to_le_bytes());
+        stream.extend((!(block.len() as u16)).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_encode() {
+        let red = Rgb(255, 0, 0);
+        let pixels = Grid::from_vec(2, vec![red, Rgb::default(), Rgb::default(), red]).unwrap();
+        let scaled = scale(&pixels, 2);
+        assert_eq!((scaled.width(), scaled.height()), (4, 4));
+        assert_eq!(
+            scaled.row(1).unwrap(),
+            &[red, red, Rgb::default(), Rgb::default()]
+        );
+
+        let image = ppm(&pixels);
+        assert!(image.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(image.len(), 11 + 4 * 3);
+
+        let image = png(&pixels);
+        assert!(image.starts_with(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00\x00\x02"));
+        assert!(image.ends_with(b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"));
+        // The two rows of one filter byte and two pixels each, stored in a single block.
+        let data_start = 8 + 25 + 8 + 2;
+        assert_eq!(&image[data_start..data_start + 5], &[1, 14, 0, 0xf1, 0xff]);
+
+        assert!(save(Path::new("map.gif"), &pixels).is_err());
+
+        let empty = scale(&Grid::new(0, 2, red), 2);
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert!(png(&empty).starts_with(b"\x89PNG"));
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+/// Folder below `src/YYYY` that holds the puzzle inputs.
+pub const INPUTS_FOLDER: &str = "inputs";
+
+/// Environment variable that moves the puzzle inputs out of `src/YYYY/inputs`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory of an event below `src`, e.g. `src/2022`. It holds the solutions and data folders.
+pub fn year_dir(year: i32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(year.to_string())
+}
+
+fn folder_dir(folder: &str, year: i32) -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if folder == INPUTS_FOLDER => Path::new(&dir).join(year.to_string()),
+        _ => year_dir(year).join(folder),
+    }
+}
+
+/// Path of a day's file in one of the data folders of an event, e.g. `src/2022/examples/01.txt`.
+///
+/// Paths are resolved against the crate root rather than the working directory, so binaries can
+/// be run from anywhere. The inputs can be moved by setting `AOC_INPUT_DIR`, which then holds one
+/// folder per year, e.g. `$AOC_INPUT_DIR/2022/01.txt`.
+pub fn file_path(folder: &str, year: i32, day: u8) -> PathBuf {
+    folder_dir(folder, year).join(format!("{:02}.txt", day))
+}
+
+/// Path of a named file of a day, e.g. `src/2022/inputs/01/alice.txt` for the input set `alice`.
+pub fn set_path(folder: &str, year: i32, day: u8, name: &str) -> PathBuf {
+    folder_dir(folder, year)
+        .join(format!("{:02}", day))
+        .join(format!("{}.txt", name))
+}
+
+/// Names of the input sets of a day in alphabetical order, one per `inputs/NN/<name>.txt` file.
+pub fn input_sets(year: i32, day: u8) -> io::Result<Vec<String>> {
+    let dir = folder_dir(INPUTS_FOLDER, year).join(format!("{:02}", day));
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut names = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_stem().and_then(|name| name.to_str()) {
+                names.push(name.to_owned());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Reads a file from `path`, or all of stdin if `path` is `-`.
+///
+/// Errors name the path that was tried.
+pub fn read_path(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("could not read \"{}\": {}", path.display(), e),
+        )
+    })
+}
+
+/// Brings puzzle text into canonical form: no byte order mark, `\n` line endings and exactly one
+/// trailing newline (none for empty text).
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let mut normalized = input.replace("\r\n", "\n").replace('\r', "\n");
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Reads and normalizes a day's file.
+pub fn try_read_file(folder: &str, year: i32, day: u8) -> io::Result<String> {
+    read_path(&file_path(folder, year, day)).map(|input| normalize(&input))
+}
+
+/// Reads a day's file and panics with a diagnostic if that is not possible.
+///
+/// The text is normalized, see `normalize`. Days whose parser needs the file exactly as it is
+/// stored declare `pub const NORMALIZE_INPUT: bool = false;` and read their examples with
+/// `read_file_raw`; the runners then skip normalization for their inputs as well.
+pub fn read_file(folder: &str, year: i32, day: u8) -> String {
+    normalize(&read_file_raw(folder, year, day))
+}
+
+/// Like `read_file`, but returns the file without normalizing it.
+///
+/// This always reads `file_path(folder, year, day)`. The `--input` and `--input-set` flags are
+/// resolved by the binaries, see `DayArgs::input_path`.
+pub fn read_file_raw(folder: &str, year: i32, day: u8) -> String {
+    read_path(&file_path(folder, year, day)).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_path() {
+        assert_eq!(
+            file_path("examples", 2022, 7),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/2022/examples/07.txt")
+        );
+    }
+
+    #[test]
+    fn test_set_path() {
+        assert_eq!(
+            set_path("answers", 2022, 7, "alice"),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/2022/answers/07/alice.txt")
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("\u{feff}1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3\n");
+        assert_eq!(normalize("1\n2"), "1\n2\n");
+        assert_eq!(normalize("1\n2\n\n\n"), "1\n2\n");
+        assert_eq!(
+            normalize("    [D]    \n 1   2 \n"),
+            "    [D]    \n 1   2 \n"
+        );
+        assert_eq!(normalize("\n\n"), "");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "could not read")]
+    fn test_read_file_missing() {
+        read_file("examples", 2022, 0);
+    }
+
+    #[test]
+    fn test_read_path_error() {
+        let path = file_path("examples", 2022, 0);
+        let e = read_path(&path).unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::NotFound);
+        assert!(e
+            .to_string()
+            .starts_with(&format!("could not read \"{}\": ", path.display())));
+    }
+}
@@ -3,125 +3,216 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
+// lets the solutions in `src/YYYY`, which are compiled as `days::yYYYY::dayNN`, refer to this crate by name.
+extern crate self as advent_of_code;
+
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod error;
 pub mod fs_parse;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod limits;
 pub mod point;
+pub mod puzzle;
+pub mod readme;
+pub mod report;
+pub mod result;
+pub mod search;
+pub mod submit;
+pub mod viz;
 
 pub mod helpers;
 
+pub use error::Error;
+pub use input::{file_path, read_file, read_file_raw, set_path, try_read_file, year_dir};
+pub use result::{Answer, IntoAnswer, SolveResult, Status};
+
+// rstest_reuse templates in the solutions' tests resolve it from the crate root.
+#[cfg(test)]
+#[allow(clippy::single_component_path_imports)]
+use rstest_reuse;
+
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Flags accepted by `cargo solve` and `cargo all`, e.g. `cargo solve 01 -- --record`.
+#[derive(Debug, Default, Clone)]
+pub struct DayArgs {
+    /// Store the answers in `src/YYYY/answers` instead of verifying them.
+    pub record: bool,
+    /// Run every part this many times and report timing statistics.
+    pub bench: Option<usize>,
+    /// Save the `--bench` statistics as the baseline for later runs.
+    pub save_baseline: bool,
+    /// Read the puzzle input from this path instead of `src/YYYY/inputs`; `-` reads stdin.
+    pub input: Option<PathBuf>,
+    /// Read the named input `src/YYYY/inputs/NN/<name>.txt` and verify it against its own answers.
+    pub input_set: Option<String>,
+    /// Let days that can draw their solution print it, e.g. the route of day 12.
+    pub render: bool,
+    /// Colour what `--render` prints.
+    pub color: bool,
+    /// Save what days that can draw their solution draw as a PNG or PPM image.
+    pub image: Option<PathBuf>,
+    /// Let days that simulate something show it step by step in the terminal, see `viz`.
+    pub animate: bool,
+    /// Frames per second of `--animate`, 0 for as fast as possible.
+    pub fps: Option<f64>,
+    /// The part of the simulation `--animate` shows, as `x,y,width,height`.
+    pub viewport: Option<viz::Viewport>,
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+impl DayArgs {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            record: args.contains("--record"),
+            bench: args.opt_value_from_str("--bench")?,
+            save_baseline: args.contains("--save-baseline"),
+            input: args.opt_value_from_str("--input")?,
+            input_set: args.opt_value_from_str("--input-set")?,
+            render: args.contains("--render"),
+            color: args.contains("--color"),
+            image: args.opt_value_from_str("--image")?,
+            animate: args.contains("--animate"),
+            fps: args.opt_value_from_str("--fps")?,
+            viewport: args.opt_value_from_str("--viewport")?,
+        })
+    }
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
+    /// The input file of a day: the `--input` path, the `--input-set` file or the default input.
+    pub fn input_path(&self, year: i32, day: u8) -> PathBuf {
+        match (&self.input, &self.input_set) {
+            (Some(path), _) => path.clone(),
+            (None, Some(name)) => set_path(input::INPUTS_FOLDER, year, day, name),
+            (None, None) => file_path(input::INPUTS_FOLDER, year, day),
+        }
+    }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+    pub fn from_env() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+        match Self::parse(&mut args) {
+            Ok(args) => args,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                process::exit(1);
+            }
+        }
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+/// Runs a single part as requested by `args`, then verifies or records its answer.
+pub fn solve_part<R: IntoAnswer>(
+    year: i32,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> R,
+    input: &str,
+    args: &DayArgs,
+) -> SolveResult {
+    let mut result = match args.bench {
+        Some(iterations) => bench::run_part(year, day, part, solver, input, iterations),
+        None => run_part(year, day, part, solver, input),
+    };
+
+    if let Some(benchmark) = &mut result.benchmark {
+        let input_set = args.input_set.as_deref();
+        benchmark.baseline = bench::read_baseline(year, day, part, input_set);
+        if args.save_baseline {
+            if let Err(e) = bench::save_baseline(year, day, part, input_set, &benchmark.stats) {
+                eprintln!("Failed to save baseline: {}", e);
             }
         }
-    })
+    }
+
+    check_answer(result, args)
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
+/// Runs one part of a day, prints it and evaluates to its `SolveResult`.
+///
+/// This is what the per-day binaries in `src/bin/NN.rs` used before the solutions moved to
+/// `src/YYYY/NN.rs`. It still works in a binary of your own, and solves the day in the year
+/// `cargo solve` would pick. The oldest form, `solve!(part, solver, input)`, does not name the day,
+/// so its answer is only timed and printed, not verified.
+#[deprecated(
+    since = "0.9.0",
+    note = "solutions are registered from `src/YYYY/NN.rs` and run with `cargo solve <day>`"
+)]
+#[macro_export]
+macro_rules! solve {
+    ($day:expr, $part:expr, $solver:ident, $input:expr) => {{
+        let day: u8 = $day;
+        let year = $crate::days::latest_year(day).unwrap_or_else($crate::client::default_year);
+        let args = $crate::DayArgs::from_env();
+        let result = $crate::solve_part(year, day, $part, $solver, $input, &args);
+        println!("{}", result);
+        result
     }};
+    ($part:expr, $solver:ident, $input:expr) => {{
+        let result = $crate::run_part(0, 0, $part, $solver, $input);
+        println!("{}", result);
+        result
+    }};
+}
+
+/// Runs a single part and times the solver.
+pub fn run_part<R: IntoAnswer>(
+    year: i32,
+    day: u8,
+    part: u8,
+    solver: impl FnOnce(&str) -> R,
+    input: &str,
+) -> SolveResult {
+    let timer = Instant::now();
+    let result = solver(input);
+    let elapsed = timer.elapsed();
+
+    SolveResult::from_answer(year, day, part, result.into_answer(), elapsed)
+}
+
+/// Verifies a result against the recorded answers of its day, or records it if requested.
+///
+/// Answers are only recorded for the real input and the named input sets, so results for an
+/// `--input` are left untouched.
+pub fn check_answer(mut result: SolveResult, args: &DayArgs) -> SolveResult {
+    if args.input.is_some() {
+        return result;
+    }
+
+    let input_set = args.input_set.as_deref();
+    result.input_set = args.input_set.clone();
+    let mut answers =
+        answers::Answers::read(result.year, result.day, input_set).unwrap_or_default();
+
+    if !args.record {
+        return answers.verify(result);
+    }
+
+    answers.record(&result);
+    if let Err(e) = answers.write(result.year, result.day, input_set) {
+        eprintln!("Failed to record answer: {}", e);
+    }
+    result
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    fn part_one(input: &str) -> Option<usize> {
+        Some(input.len())
+    }
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    #[allow(deprecated)]
+    fn test_solve_shim() {
+        // The form of the original per-day binaries, which did not name their day.
+        let result = crate::solve!(1, part_one, "abc");
+        assert_eq!((result.part, result.answer), (1, Some("3".to_owned())));
     }
 }
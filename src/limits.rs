@@ -0,0 +1,336 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io::{self, Read};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{Error, SolveResult, Status};
+
+/// How often a limited process is checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Line that separates anything a solver printed from the encoded result, see `encode`.
+const RESULT_MARKER: &str = "--- advent_of_code part result ---";
+
+/// Resources a single part may use before it is stopped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Wall-clock time, including reading and preparing the input.
+    pub timeout: Option<Duration>,
+    /// Resident set size in bytes.
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_empty(&self) -> bool {
+        self.timeout.is_none() && self.memory.is_none()
+    }
+}
+
+/// Parses a timeout such as `500ms`, `10s` or `2m`. A plain number is taken as seconds.
+pub fn parse_timeout(value: &str) -> Result<Duration, String> {
+    let (number, unit) = split_unit(value);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid timeout \"{}\", expected e.g. 500ms or 10s", value))?;
+
+    let seconds = match unit {
+        "ms" => number / 1000_f64,
+        "" | "s" => number,
+        "m" => number * 60_f64,
+        _ => return Err(format!("unknown unit \"{}\", expected ms, s or m", unit)),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|e| format!("invalid timeout \"{}\": {}", value, e))
+}
+
+/// Parses a memory size such as `512M` or `2G` into bytes. Units are binary, a plain number is bytes.
+pub fn parse_memory(value: &str) -> Result<u64, String> {
+    let (number, unit) = split_unit(value);
+    let number: u64 = number.parse().map_err(|_| {
+        format!(
+            "invalid memory limit \"{}\", expected e.g. 512M or 2G",
+            value
+        )
+    })?;
+
+    let factor = match unit.to_ascii_uppercase().trim_end_matches(['B', 'I']) {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        _ => return Err(format!("unknown unit \"{}\", expected K, M or G", unit)),
+    };
+    Ok(number * factor)
+}
+
+fn split_unit(value: &str) -> (&str, &str) {
+    let value = value.trim();
+    let idx = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    value.split_at(idx)
+}
+
+/// Resident set size of a running process in bytes, read from `/proc`. `None` where that is not
+/// available, so memory limits are only enforced on Linux.
+pub fn rss(pid: u32) -> Option<u64> {
+    let status = fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let kilobytes = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// How a limited process ended.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Exited {
+        success: bool,
+        stdout: String,
+        stderr: String,
+    },
+    /// Killed after running for longer than the timeout.
+    TimedOut(Duration),
+    /// Killed after its resident set size exceeded the memory limit.
+    OutOfMemory(Duration),
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = vec![];
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut output);
+        }
+        String::from_utf8_lossy(&output).into_owned()
+    })
+}
+
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Runs a command and kills it as soon as it exceeds one of the limits.
+pub fn run(command: &mut Command, limits: &Limits) -> io::Result<Outcome> {
+    let timer = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // The pipes are read while waiting, so a chatty process cannot block on a full pipe.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Outcome::Exited {
+                success: status.success(),
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            });
+        }
+
+        let elapsed = timer.elapsed();
+        if limits.timeout.is_some_and(|timeout| elapsed > timeout) {
+            kill(&mut child);
+            return Ok(Outcome::TimedOut(elapsed));
+        }
+        if let (Some(memory), Some(rss)) = (limits.memory, rss(child.id())) {
+            if rss > memory {
+                kill(&mut child);
+                return Ok(Outcome::OutOfMemory(elapsed));
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Encodes the result of a part for the process that runs it under limits.
+///
+/// The format is a marker line, then `<status> <elapsed_ns>`, then the answer, or the kind of error
+/// followed by its message.
+pub fn encode(result: &SolveResult) -> String {
+    let mut encoded = format!(
+        "{}\n{} {}\n",
+        RESULT_MARKER,
+        result.status,
+        result.elapsed.as_nanos()
+    );
+    match (&result.answer, &result.error) {
+        (Some(answer), _) => encoded.push_str(answer),
+        (
+            None,
+            Some(Error::Parse {
+                line,
+                column,
+                message,
+            }),
+        ) => encoded.push_str(&format!("parse {} {}\n{}", line, column, message)),
+        (None, Some(Error::InvalidState(message))) => {
+            encoded.push_str(&format!("invalid_state\n{}", message))
+        }
+        (None, None) => {}
+    }
+    encoded
+}
+
+/// Reads a result written by `encode`, ignoring anything the solver printed before it.
+pub fn decode(year: i32, day: u8, part: u8, output: &str) -> Option<SolveResult> {
+    let (_, encoded) = output.rsplit_once(&format!("{}\n", RESULT_MARKER))?;
+    let encoded = encoded.strip_suffix('\n').unwrap_or(encoded);
+    let (header, body) = encoded.split_once('\n').unwrap_or((encoded, ""));
+    let (status, elapsed) = header.split_once(' ')?;
+    let elapsed = Duration::from_nanos(elapsed.parse().ok()?);
+
+    match status {
+        "solved" => Some(SolveResult::solved(
+            year,
+            day,
+            part,
+            body.to_owned(),
+            elapsed,
+        )),
+        "unsolved" => Some(SolveResult::unsolved(year, day, part, elapsed)),
+        "error" => {
+            let (kind, message) = body.split_once('\n').unwrap_or((body, ""));
+            let error = match kind.split(' ').collect::<Vec<_>>()[..] {
+                ["parse", line, column] => Error::Parse {
+                    line: line.parse().ok()?,
+                    column: column.parse().ok()?,
+                    message: message.to_owned(),
+                },
+                ["invalid_state"] => Error::InvalidState(message.to_owned()),
+                _ => return None,
+            };
+            Some(SolveResult::failed(year, day, part, error, elapsed))
+        }
+        _ => None,
+    }
+}
+
+/// Turns the outcome of a part's process into its result.
+pub fn to_result(year: i32, day: u8, part: u8, outcome: Outcome) -> SolveResult {
+    match outcome {
+        Outcome::TimedOut(elapsed) => {
+            SolveResult::limited(year, day, part, Status::Timeout, elapsed)
+        }
+        Outcome::OutOfMemory(elapsed) => {
+            SolveResult::limited(year, day, part, Status::Oom, elapsed)
+        }
+        Outcome::Exited {
+            success,
+            stdout,
+            stderr,
+        } => match decode(year, day, part, &stdout).filter(|_| success) {
+            Some(result) => result,
+            None => {
+                // A panic or an abort, e.g. a failed allocation. Its message is the last thing printed.
+                let message = stderr
+                    .lines()
+                    .rev()
+                    .find(|line| !line.trim().is_empty() && !line.starts_with("note:"))
+                    .unwrap_or("the process exited without a result");
+                let error = Error::invalid_state(format!("the solver crashed: {}", message.trim()));
+                SolveResult::failed(year, day, part, error, Duration::ZERO)
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_limits() {
+        assert_eq!(parse_timeout("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_timeout("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_timeout("2m"), Ok(Duration::from_secs(120)));
+        assert!(parse_timeout("2h").is_err());
+
+        assert_eq!(parse_memory("512M"), Ok(512 << 20));
+        assert_eq!(parse_memory("2GiB"), Ok(2 << 30));
+        assert_eq!(parse_memory("4096"), Ok(4096));
+        assert!(parse_memory("lots").is_err());
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let results = [
+            SolveResult::solved(2022, 5, 1, "CMZ".to_owned(), Duration::from_nanos(1500)),
+            SolveResult::unsolved(2022, 5, 2, Duration::from_nanos(10)),
+            SolveResult::failed(
+                2022,
+                5,
+                2,
+                Error::Parse {
+                    line: 3,
+                    column: 7,
+                    message: "expected a digit".to_owned(),
+                },
+                Duration::from_nanos(20),
+            ),
+            SolveResult::failed(
+                2022,
+                5,
+                2,
+                Error::invalid_state("no crates"),
+                Duration::ZERO,
+            ),
+        ];
+
+        for result in results {
+            let output = format!("printed by the solver\n{}", encode(&result));
+            assert_eq!(decode(2022, 5, result.part, &output), Some(result));
+        }
+        assert_eq!(decode(2022, 5, 1, "no result\n"), None);
+    }
+
+    #[test]
+    fn test_run() {
+        let outcome = run(
+            Command::new("sh").args(["-c", "echo ok"]),
+            &Limits::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            outcome,
+            Outcome::Exited {
+                success: true,
+                stdout: "ok\n".to_owned(),
+                stderr: String::new()
+            }
+        );
+
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(50)),
+            memory: None,
+        };
+        let outcome = run(Command::new("sleep").arg("5"), &limits).unwrap();
+        assert!(matches!(outcome, Outcome::TimedOut(elapsed) if elapsed < Duration::from_secs(5)));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_run_memory_limit() {
+        // `tail` buffers the never-ending first line of /dev/zero.
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(10)),
+            memory: Some(16 << 20),
+        };
+        let outcome = run(Command::new("tail").arg("/dev/zero"), &limits).unwrap();
+        assert!(matches!(outcome, Outcome::OutOfMemory(_)));
+    }
+}
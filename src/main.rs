@@ -2,45 +2,360 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::limits::{self, Limits};
+use advent_of_code::readme;
+use advent_of_code::report::{self, Format};
+use advent_of_code::{
+    days, input, DayArgs, Error, SolveResult, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+struct Args {
+    format: Format,
+    /// Only run the solutions of this year.
+    year: Option<i32>,
+    /// Run every day against each of its named inputs instead of the default input.
+    input_sets: bool,
+    /// Number of days that are run at the same time.
+    jobs: usize,
+    /// Per-part limits, enforced by running every part in its own process.
+    limits: Limits,
+    /// Regenerate the stats section of the README from the results.
+    readme: bool,
+    day_args: DayArgs,
+}
+
+/// Hidden first argument the runner re-executes itself with to run one part under its limits.
+const WORKER_ARG: &str = "--run-part";
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        input_sets: args.contains("--input-sets"),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+        limits: Limits {
+            timeout: args.opt_value_from_fn("--timeout", limits::parse_timeout)?,
+            memory: args.opt_value_from_fn("--memory-limit", limits::parse_memory)?,
+        },
+        readme: args.contains("--readme"),
+        day_args: DayArgs::parse(&mut args)?,
+    })
+}
+
+fn run_day(day: &days::Day, day_args: &DayArgs, limits: &Limits) -> Vec<SolveResult> {
+    let input_path = day_args.input_path(day.year, day.day);
+    match input::read_path(&input_path) {
+        Ok(_) if !limits.is_empty() => (1..=2)
+            .map(|part| {
+                let result = run_limited(day, part, &input_path, limits);
+                advent_of_code::check_answer(result, day_args)
+            })
+            .collect(),
+        Ok(input) => {
+            let input = day.prepare_input(input);
+            vec![
+                advent_of_code::solve_part(day.year, day.day, 1, day.part_one, &input, day_args),
+                advent_of_code::solve_part(day.year, day.day, 2, day.part_two, &input, day_args),
+            ]
+        }
+        Err(_) => vec![
+            SolveResult::no_input(day.year, day.day, 1),
+            SolveResult::no_input(day.year, day.day, 2),
+        ],
+    }
+}
+
+/// Runs a part in a child process of the runner that is stopped when it exceeds the limits.
+fn run_limited(day: &days::Day, part: u8, input_path: &Path, limits: &Limits) -> SolveResult {
+    let outcome = env::current_exe().and_then(|exe| {
+        let mut command = Command::new(exe);
+        command
+            .arg(WORKER_ARG)
+            .args([day.year.to_string(), day.day.to_string(), part.to_string()])
+            .arg(input_path);
+        limits::run(&mut command, limits)
+    });
+
+    match outcome {
+        Ok(outcome) => limits::to_result(day.year, day.day, part, outcome),
+        Err(e) => {
+            let error = Error::invalid_state(format!("could not start the part: {}", e));
+            SolveResult::failed(day.year, day.day, part, error, Duration::ZERO)
+        }
+    }
+}
+
+/// Entry point of the child process started by `run_limited`: runs one part and prints its result.
+fn run_worker() -> Result<(), String> {
+    let mut args = pico_args::Arguments::from_env();
+    args.contains(WORKER_ARG);
+    let parse =
+        |args: &mut pico_args::Arguments| -> Result<(i32, u8, u8, PathBuf), pico_args::Error> {
+            Ok((
+                args.free_from_str()?,
+                args.free_from_str()?,
+                args.free_from_str()?,
+                args.free_from_str()?,
+            ))
+        };
+    let (year, day, part, input_path) = parse(&mut args).map_err(|e| e.to_string())?;
+
+    let day = days::get(year, day)
+        .ok_or_else(|| format!("There is no solution for day {} of {}.", day, year))?;
+    let input = input::read_path(&input_path).map_err(|e| e.to_string())?;
+    let input = day.prepare_input(input);
+
+    let solver = if part == 1 {
+        day.part_one
+    } else {
+        day.part_two
+    };
+    let result = advent_of_code::run_part(day.year, day.day, part, solver, &input);
+    print!("{}", limits::encode(&result));
+    Ok(())
+}
+
+/// A day to run, against the default input or one of its input sets.
+struct Job {
+    day: &'static days::Day,
+    day_args: DayArgs,
+}
+
+/// The selected days, once per input set with `--input-sets`.
+fn jobs(args: &Args) -> Vec<Job> {
+    let days = days::DAYS
+        .iter()
+        .filter(|day| args.year.is_none_or(|year| day.year == year));
+
+    if !args.input_sets {
+        return days
+            .map(|day| Job {
+                day,
+                day_args: args.day_args.clone(),
+            })
+            .collect();
+    }
+
+    days.flat_map(|day| {
+        let names = input::input_sets(day.year, day.day).unwrap_or_else(|e| {
+            eprintln!(
+                "Failed to list the input sets of {} day {}: {}",
+                day.year, day.day, e
+            );
+            vec![]
+        });
+        names.into_iter().map(move |name| Job {
+            day,
+            day_args: DayArgs {
+                input_set: Some(name),
+                ..args.day_args.clone()
+            },
+        })
+    })
+    .collect()
+}
+
+/// Runs the jobs on `threads` worker threads and passes their results to `done` in job order.
+///
+/// Every part is timed on the thread that runs it, so timings stay accurate as long as there are
+/// no more threads than cores.
+fn run_jobs(
+    jobs: &[Job],
+    threads: usize,
+    limits: &Limits,
+    mut done: impl FnMut(&Job, Vec<SolveResult>),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.min(jobs.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(idx) else {
+                    break;
+                };
+                let _ = sender.send((idx, run_day(job.day, &job.day_args, limits)));
+            });
+        }
+        drop(sender);
+
+        // Jobs finish out of order, hold their results back until all earlier jobs are done.
+        let mut finished = BTreeMap::new();
+        let mut printed = 0;
+        for (idx, results) in receiver {
+            finished.insert(idx, results);
+            while let Some(results) = finished.remove(&printed) {
+                done(&jobs[printed], results);
+                printed += 1;
+            }
+        }
+    });
+}
+
+fn print_day(day: &days::Day, input_set: Option<&str>, results: &[SolveResult]) {
+    let title = match input_set {
+        Some(name) => format!("| {} Day {:02} · {} |", day.year, day.day, name),
+        None => format!("| {} Day {:02} |", day.year, day.day),
+    };
+    let line = "-".repeat(title.chars().count());
+    println!("{}", line);
+    println!("{}{}{}", ANSI_BOLD, title, ANSI_RESET);
+    println!("{}", line);
+
+    if results
+        .iter()
+        .all(|result| result.status == Status::NoInput)
+    {
+        println!("Not solved.");
+    } else {
+        for result in results {
+            println!("{}", result);
+        }
+    }
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+    if env::args().nth(1).as_deref() == Some(WORKER_ARG) {
+        if let Err(e) = run_worker() {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    let mut args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+    if args.day_args.input.is_some() {
+        eprintln!("`--input` selects the input of a single day, use `cargo solve <day> -- --input <path>` instead.");
+        process::exit(1);
+    }
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+    if args.input_sets && args.day_args.input_set.is_some() {
+        eprintln!("`--input-sets` runs every input set, it cannot be combined with `--input-set`.");
+        process::exit(1);
+    }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    if let Some(year) = args.year {
+        if !days::years().contains(&year) {
+            eprintln!("There are no solutions for {} in src/{}.", year, year);
+            process::exit(1);
+        }
+    }
+
+    if args.readme && (args.year.is_some() || args.input_sets || args.day_args.input_set.is_some())
+    {
+        eprintln!("`--readme` needs the results of every day for the default inputs, it cannot be combined with `--year`, `--input-set` or `--input-sets`.");
+        process::exit(1);
+    }
+
+    if !args.limits.is_empty() && args.day_args.bench.is_some() {
+        eprintln!("`--bench` cannot be combined with `--timeout` or `--memory-limit`.");
+        process::exit(1);
+    }
+
+    // The README shows the median runtime, so its parts are run several times. Only an explicit
+    // `--bench` replaces the runtimes that are already in it.
+    let refresh_runtimes = args.day_args.bench.is_some();
+    if args.readme && args.day_args.bench.is_none() && args.limits.is_empty() {
+        args.day_args.bench = Some(readme::RUNS);
+    }
+
+    if args.day_args.render || args.day_args.image.is_some() || args.day_args.animate {
+        eprintln!("`--render`, `--image` and `--animate` show the solution of a single day, use `cargo solve <day> -- --render` instead.");
+        process::exit(1);
+    }
+
+    if args.jobs == 0 {
+        eprintln!("`--jobs` needs at least one thread.");
+        process::exit(1);
+    }
+    let cores = thread::available_parallelism().map_or(1, |cores| cores.get());
+    if args.jobs > cores {
+        eprintln!(
+            "Running {} jobs on {} cores, timings will include waiting for a core.",
+            args.jobs, cores
+        );
+    }
+
+    let jobs = jobs(&args);
+    if args.input_sets && jobs.is_empty() {
+        eprintln!("There are no input sets, add them as `src/YYYY/inputs/NN/<name>.txt`.");
+        process::exit(1);
+    }
+
+    let timer = Instant::now();
+    let mut results: Vec<SolveResult> = vec![];
+    run_jobs(&jobs, args.jobs, &args.limits, |job, day_results| {
+        if args.format == Format::Text {
+            print_day(job.day, job.day_args.input_set.as_deref(), &day_results);
+        }
+        results.extend(day_results);
+    });
+    let wall_time = timer.elapsed();
+
+    match args.format {
+        Format::Text => {
+            let total: Duration = results.iter().map(|result| result.elapsed).sum();
 
             println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+                "{}Total:{} {}{:.2}ms{}",
+                ANSI_BOLD,
+                ANSI_RESET,
+                ANSI_ITALIC,
+                total.as_secs_f64() * 1000_f64,
+                ANSI_RESET
             );
+            if args.jobs > 1 {
+                println!(
+                    "{}Wall time:{} {}{:.2}ms{} ({} jobs)",
+                    ANSI_BOLD,
+                    ANSI_RESET,
+                    ANSI_ITALIC,
+                    wall_time.as_secs_f64() * 1000_f64,
+                    ANSI_RESET,
+                    args.jobs
+                );
+            }
+        }
+        Format::Json => println!("{}", report::to_json(&results)),
+        Format::Csv => print!("{}", report::to_csv(&results)),
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    if args.readme {
+        match readme::update(&results, refresh_runtimes) {
+            Ok(true) => eprintln!("Updated the stats in README.md."),
+            Ok(false) => eprintln!("The stats in README.md are up to date."),
+            Err(e) => {
+                eprintln!("Failed to update README.md: {}", e);
+                process::exit(1);
             }
-        })
-        .sum();
+        }
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if results.iter().any(|result| {
+        matches!(
+            result.status,
+            Status::Mismatch | Status::Error | Status::Timeout | Status::Oom
+        )
+    }) {
+        process::exit(1);
+    }
 }
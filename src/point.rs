@@ -1,25 +1,470 @@
-#[derive(Default, Debug, Hash, PartialEq, Eq, Clone, Copy)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The integer types points can be made of.
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Default
+    + Hash
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The type of distances, e.g. `u32` for `i32`.
+    type Unsigned: Copy + Add<Output = Self::Unsigned> + Ord;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+    fn signum(self) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! coordinate {
+    ($($signed:ty => $unsigned:ty),*) => {$(
+        impl Coordinate for $signed {
+            type Unsigned = $unsigned;
+
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_diff(self, other: Self) -> $unsigned {
+                <$signed>::abs_diff(self, other)
+            }
+
+            fn signum(self) -> Self {
+                <$signed>::signum(self)
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
 }
 
-impl Point {
-    pub fn x_distance(&self, point_b: &Point) -> u32 {
+coordinate!(i32 => u32, i64 => u64);
+
+/// A point on a plane. Where the plane is a map, `y` grows downwards, see `Direction`.
+#[derive(Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn x_distance(&self, point_b: &Self) -> T::Unsigned {
         self.x.abs_diff(point_b.x)
     }
 
-    pub fn y_distance(&self, point_b: &Point) -> u32 {
+    pub fn y_distance(&self, point_b: &Self) -> T::Unsigned {
         self.y.abs_diff(point_b.y)
     }
 
-    pub fn manhattan_distance(&self, point_b: &Point) -> u32 {
+    pub fn manhattan_distance(&self, point_b: &Self) -> T::Unsigned {
         self.x_distance(point_b) + self.y_distance(point_b)
     }
 
-    pub fn distance(&self, point_b: &Point) -> f32 {
-        let x_distance = self.x_distance(point_b);
-        let y_distance = self.y_distance(point_b);
-        ((x_distance as f32).powi(2) + (y_distance as f32).powi(2)).sqrt()
+    /// The number of king moves between two points, so 1 for every one of the 8 neighbours.
+    pub fn chebyshev_distance(&self, point_b: &Self) -> T::Unsigned {
+        self.x_distance(point_b).max(self.y_distance(point_b))
+    }
+
+    pub fn distance(&self, point_b: &Self) -> f32 {
+        // Widened before subtracting, so points at opposite ends of the range do not overflow.
+        let x_distance = self.x.to_f64() - point_b.x.to_f64();
+        let y_distance = self.y.to_f64() - point_b.y.to_f64();
+        (x_distance.powi(2) + y_distance.powi(2)).sqrt() as f32
+    }
+
+    /// The point with the sign of each coordinate, i.e. a single step towards it from the origin.
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The orthogonal neighbours, clockwise starting with the one above.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction.offset())
+    }
+
+    /// The orthogonal and diagonal neighbours, clockwise starting with the one above.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Compass::ALL
+            .into_iter()
+            .map(move |compass| self + compass.offset())
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Coordinate> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coordinate> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, factor: T) {
+        *self = *self * factor;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+/// One of the 4 orthogonal directions on a map, where up means towards smaller `y`.
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step to take to move one cell into this direction.
+    pub fn offset<T: Coordinate>(self) -> Point<T> {
+        Compass::from(self).offset()
+    }
+
+    /// The direction of a single step from `from` to its neighbour `to`.
+    pub fn between<T: Coordinate>(from: Point<T>, to: Point<T>) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|direction| from + direction.offset() == to)
+    }
+
+    /// The arrow that points into this direction, as in the puzzle illustrations.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// One of the 8 compass directions on a map, where north means towards smaller `y`.
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Compass {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Compass {
+    /// Clockwise, starting with `N`.
+    pub const ALL: [Compass; 8] = [
+        Compass::N,
+        Compass::NE,
+        Compass::E,
+        Compass::SE,
+        Compass::S,
+        Compass::SW,
+        Compass::W,
+        Compass::NW,
+    ];
+
+    /// Turns by 45 degrees.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The step to take to move one cell into this direction.
+    pub fn offset<T: Coordinate>(self) -> Point<T> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        let (x, y) = match self {
+            Compass::N => (zero, -one),
+            Compass::NE => (one, -one),
+            Compass::E => (one, zero),
+            Compass::SE => (one, one),
+            Compass::S => (zero, one),
+            Compass::SW => (-one, one),
+            Compass::W => (-one, zero),
+            Compass::NW => (-one, -one),
+        };
+        Point::new(x, y)
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::N,
+            Direction::Right => Compass::E,
+            Direction::Down => Compass::S,
+            Direction::Left => Compass::W,
+        }
+    }
+}
+
+/// A point in space.
+#[derive(Default, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, point_b: &Self) -> T::Unsigned {
+        self.x.abs_diff(point_b.x) + self.y.abs_diff(point_b.y) + self.z.abs_diff(point_b.z)
+    }
+
+    /// The smallest coordinates of both points, i.e. a corner of their bounding box.
+    pub fn min_components(&self, point_b: &Self) -> Self {
+        Self::new(
+            self.x.min(point_b.x),
+            self.y.min(point_b.y),
+            self.z.min(point_b.z),
+        )
+    }
+
+    /// The largest coordinates of both points, i.e. a corner of their bounding box.
+    pub fn max_components(&self, point_b: &Self) -> Self {
+        Self::new(
+            self.x.max(point_b.x),
+            self.y.max(point_b.y),
+            self.z.max(point_b.z),
+        )
+    }
+
+    /// The 6 points that share a face with this one.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::ZERO, T::ONE);
+        [
+            Self::new(one, zero, zero),
+            Self::new(-one, zero, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, -one, zero),
+            Self::new(zero, zero, one),
+            Self::new(zero, zero, -one),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Coordinate> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(1, 4);
+        assert_eq!(a + b, Point::new(4, 2));
+        assert_eq!(a - b, Point::new(2, -6));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+
+        let mut c = a;
+        c += b;
+        c -= Point::new(0, 1);
+        c *= 2;
+        assert_eq!(c, Point::new(8, 2));
+
+        let big: Point<i64> = Point::new(3_000_000_000, 0);
+        assert_eq!(big.manhattan_distance(&Point::new(0, 4)), 3_000_000_004_u64);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(0, 0);
+        let b = Point::new(3, -4);
+        assert_eq!(a.manhattan_distance(&b), 7_u32);
+        assert_eq!(a.chebyshev_distance(&b), 4_u32);
+        assert_eq!(a.distance(&b), 5_f32);
+
+        let low = Point::new(i32::MIN, 0);
+        let high = Point::new(i32::MAX, 0);
+        assert_eq!(low.distance(&high), u32::MAX as f32);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::Down.offset::<i32>(), Point::new(0, 1));
+        assert_eq!(
+            Direction::between(Point::new(2, 2), Point::new(1, 2)),
+            Some(Direction::Left)
+        );
+        assert_eq!(Direction::between(Point::new(2, 2), Point::new(1, 1)), None);
+        assert_eq!(Direction::Left.arrow(), '<');
+
+        assert_eq!(Compass::N.turn_left(), Compass::NW);
+        assert_eq!(Compass::NW.turn_right(), Compass::N);
+        assert_eq!(Compass::SE.opposite(), Compass::NW);
+        assert_eq!(Compass::SW.offset::<i64>(), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let point = Point::new(5, 5);
+        assert_eq!(
+            point.neighbors4().collect::<Vec<_>>(),
+            vec![
+                Point::new(5, 4),
+                Point::new(6, 5),
+                Point::new(5, 6),
+                Point::new(4, 5)
+            ]
+        );
+        assert_eq!(point.neighbors8().count(), 8);
+        assert!(point
+            .neighbors8()
+            .all(|neighbor| neighbor.chebyshev_distance(&point) == 1_u32));
+
+        let point = Point3::new(0, 0, 0);
+        assert_eq!(point.neighbors6().count(), 6);
+        assert!(point
+            .neighbors6()
+            .all(|neighbor| neighbor.manhattan_distance(&point) == 1_u32));
+        assert_eq!(
+            Point3::new(1, -2, 3).max_components(&Point3::new(0, 5, -1)),
+            Point3::new(1, 5, 3)
+        );
     }
 }
@@ -0,0 +1,417 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+
+use crate::client::DEFAULT_BASE_URL;
+
+/// Folder below `src/YYYY` that holds the puzzle descriptions, one `NN.md` file per day.
+pub const PUZZLES_FOLDER: &str = "puzzles";
+
+pub fn markdown_path(year: i32, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join(PUZZLES_FOLDER)
+        .join(format!("{:02}.md", day))
+}
+
+/// The `<article class="day-desc">` elements of a puzzle page, one per unlocked part.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let body = &rest[start + open_end + 1..];
+        let end = body.find("</article>").unwrap_or(body.len());
+        articles.push(&body[..end]);
+        rest = &body[end..];
+    }
+
+    articles
+}
+
+/// Replaces the character references that occur in puzzle pages.
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let name = &rest[1..end];
+            let c = match name {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => name
+                    .strip_prefix("#x")
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| name.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+enum Token<'a> {
+    /// Tag name in lowercase, and the raw attributes.
+    Open(String, &'a str),
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+        } else if !tag.starts_with('!') {
+            let tag = tag.trim_end_matches('/');
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name.to_lowercase(), attrs));
+        }
+    }
+
+    tokens
+}
+
+/// Value of an attribute in a raw attribute string, e.g. `href` in `href="/2022/day/1/input"`.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = attrs[start..].find('"')?;
+    Some(&attrs[start..start + len])
+}
+
+/// Converts the puzzle descriptions of a page to Markdown.
+///
+/// Only the handful of elements puzzle pages use are translated; other tags are dropped and their
+/// text is kept.
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+
+    for article in articles(html) {
+        let mut pre = false;
+        let mut code = false;
+        let mut links = vec![];
+
+        for token in tokenize(article) {
+            match token {
+                Token::Open(name, attrs) => match name.as_str() {
+                    "h2" => markdown.push_str("## "),
+                    "pre" => {
+                        pre = true;
+                        markdown.push_str("```\n");
+                    }
+                    "code" if !pre => {
+                        code = true;
+                        markdown.push('`');
+                    }
+                    "em" if !pre && !code => markdown.push_str("**"),
+                    "li" => markdown.push_str("- "),
+                    "br" => markdown.push('\n'),
+                    "a" => {
+                        let href = attribute(attrs, "href").unwrap_or_default();
+                        let href = if href.starts_with('/') {
+                            format!("{}{}", DEFAULT_BASE_URL, href)
+                        } else {
+                            href.to_owned()
+                        };
+                        links.push(href);
+                        markdown.push('[');
+                    }
+                    _ => {}
+                },
+                Token::Close(name) => match name.as_str() {
+                    "h2" | "p" | "ul" => markdown.push_str("\n\n"),
+                    "li" => markdown.push('\n'),
+                    "pre" => {
+                        pre = false;
+                        if !markdown.ends_with('\n') {
+                            markdown.push('\n');
+                        }
+                        markdown.push_str("```\n\n");
+                    }
+                    "code" if !pre => {
+                        code = false;
+                        markdown.push('`');
+                    }
+                    "em" if !pre && !code => markdown.push_str("**"),
+                    "a" => {
+                        let href = links.pop().unwrap_or_default();
+                        markdown.push_str(&format!("]({})", href));
+                    }
+                    _ => {}
+                },
+                Token::Text(text) if pre => markdown.push_str(&decode_entities(text)),
+                Token::Text(text) => {
+                    let text = decode_entities(text);
+                    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if text.starts_with(char::is_whitespace)
+                        && !markdown.ends_with([' ', '\n', '['])
+                    {
+                        markdown.push(' ');
+                    }
+                    markdown.push_str(&collapsed);
+                    if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                        markdown.push(' ');
+                    }
+                }
+            }
+        }
+    }
+
+    let mut markdown: String = markdown
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown.trim().to_owned() + "\n"
+}
+
+/// The contents of the `<pre><code>` blocks of a page, in order and without duplicates.
+pub fn examples(html: &str) -> Vec<String> {
+    let mut examples: Vec<String> = vec![];
+
+    for article in articles(html) {
+        let mut rest = article;
+        while let Some(start) = rest.find("<pre><code>") {
+            let body = &rest[start + "<pre><code>".len()..];
+            let end = body.find("</code></pre>").unwrap_or(body.len());
+
+            let text: String = tokenize(&body[..end])
+                .into_iter()
+                .filter_map(|token| match token {
+                    Token::Text(text) => Some(decode_entities(text)),
+                    _ => None,
+                })
+                .collect();
+            if !examples.contains(&text) {
+                examples.push(text);
+            }
+
+            rest = &body[end..];
+        }
+    }
+
+    examples
+}
+
+/// The answer for the example in each part: the last emphasized `<code>` of its description.
+pub fn example_answers(html: &str) -> Vec<Option<String>> {
+    articles(html)
+        .into_iter()
+        .map(|article| {
+            ["<code><em>", "<em><code>"]
+                .iter()
+                .filter_map(|open| {
+                    let start = article.rfind(open)? + open.len();
+                    let len = article[start..].find('<')?;
+                    Some((start, decode_entities(&article[start..start + len])))
+                })
+                .max_by_key(|(start, _)| *start)
+                .map(|(_, answer)| answer)
+        })
+        .collect()
+}
+
+/// Formats an answer as the expected value of a scaffolded test: numbers as they are, anything
+/// else as an owned string.
+pub fn expected_value(answer: &str) -> String {
+    if answer.parse::<i64>().is_ok() {
+        format!("Some({})", answer)
+    } else {
+        format!("Some({:?}.to_owned())", answer)
+    }
+}
+
+/// Replaces the `None` placeholder of a part's test in a scaffolded module with `expected`.
+///
+/// Both the plain and the `--rstest` test layouts are recognized. Returns `None` if the test
+/// already has a value or was rewritten by hand.
+pub fn fill_expected(source: &str, part: u8, expected: &str) -> Option<String> {
+    let function = if part == 1 { "part_one" } else { "part_two" };
+
+    let assert = format!("assert_eq!({}(&input), None);", function);
+    if source.contains(&assert) {
+        return Some(source.replacen(
+            &assert,
+            &format!("assert_eq!({}(&input), {});", function, expected),
+            1,
+        ));
+    }
+
+    // The rstest layout has one `#[case::example(...)]` per part, directly above the test function.
+    let test = format!("fn test_{}(", function);
+    let test_start = source.find(&test)?;
+    let case_start = source[..test_start].rfind("#[case::example(")?;
+    let case = &source[case_start..test_start];
+    let placeholder = case.rfind(", None)]")?;
+
+    let mut filled = source.to_owned();
+    filled.replace_range(
+        case_start + placeholder..case_start + placeholder + ", None)]".len(),
+        &format!(", {})]", expected),
+    );
+    Some(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Counting Things ---</h2><p>The elves carry <em>things</em>. See <a href="/2022/day/1/input">your input</a>.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+&lt;3000&gt;
+</code></pre>
+<ul><li>The first elf carries <code>3000</code>.</li></ul>
+<p>In total, that elf carries <code><em>24000</em></code> things.</p>
+</article>
+<p>Your puzzle answer was <code>68787</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the top <em>three</em>:</p>
+<pre><code>1000
+2000
+
+&lt;3000&gt;
+</code></pre>
+<pre><code><em>CMZ</em></code></pre>
+<p>That gives <em><code>45000</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE),
+            "## --- Day 1: Counting Things ---
+
+The elves carry **things**. See [your input](https://adventofcode.com/2022/day/1/input).
+
+For example:
+
+```
+1000
+2000
+
+<3000>
+```
+
+- The first elf carries `3000`.
+
+In total, that elf carries `24000` things.
+
+## --- Part Two ---
+
+Find the top **three**:
+
+```
+1000
+2000
+
+<3000>
+```
+
+```
+CMZ
+```
+
+That gives **`45000`**.
+"
+        );
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            examples(PAGE),
+            vec!["1000\n2000\n\n<3000>\n".to_owned(), "CMZ".to_owned()]
+        );
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(
+            example_answers(PAGE),
+            vec![Some("24000".to_owned()), Some("45000".to_owned())]
+        );
+        assert_eq!(expected_value("24000"), "Some(24000)");
+        assert_eq!(expected_value("CMZ"), "Some(\"CMZ\".to_owned())");
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; &unknown"),
+            "a <b> & 'c' &unknown"
+        );
+    }
+
+    #[test]
+    fn test_fill_expected() {
+        let source = "assert_eq!(part_one(&input), None);\nassert_eq!(part_two(&input), None);\n";
+        let filled = fill_expected(source, 2, "Some(45000)").unwrap();
+        assert_eq!(
+            filled,
+            "assert_eq!(part_one(&input), None);\nassert_eq!(part_two(&input), Some(45000));\n"
+        );
+        assert_eq!(fill_expected(&filled, 2, "Some(1)"), None);
+
+        let source = r#"    #[rstest]
+    #[case::example(advent_of_code::read_file("examples", 2022, 1), None)]
+    fn test_part_one(#[case] input: String, #[case] expected: Option<u32>) {}
+
+    #[rstest]
+    #[case::example(advent_of_code::read_file("examples", 2022, 1), None)]
+    fn test_part_two(#[case] input: String, #[case] expected: Option<u32>) {}
+"#;
+        let filled = fill_expected(source, 2, "Some(45000)").unwrap();
+        assert!(filled.contains("(\"examples\", 2022, 1), None)]\n    fn test_part_one("));
+        assert!(filled.contains("(\"examples\", 2022, 1), Some(45000))]\n    fn test_part_two("));
+    }
+}
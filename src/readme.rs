@@ -0,0 +1,261 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Write;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::answers::Answers;
+use crate::{days, SolveResult, Status};
+
+/// Comments around the section of the README that `cargo all -- --readme` regenerates.
+pub const START_MARKER: &str = "<!--- advent_of_code stats start --->";
+pub const END_MARKER: &str = "<!--- advent_of_code stats end --->";
+
+/// Runs per part with `--readme`, unless `--bench` asks for another number. The median of them is
+/// the runtime in the table.
+pub const RUNS: usize = 10;
+
+pub fn path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md")
+}
+
+/// The time shown for a part: the median of its runs, or the time of its single run when it was
+/// run with limits.
+fn runtime(result: &SolveResult) -> Duration {
+    result
+        .benchmark
+        .as_ref()
+        .map_or(result.elapsed, |benchmark| benchmark.stats.median)
+}
+
+/// One table cell: a star for a solved part, followed by its runtime if it was run.
+///
+/// Without an input, which is the case in a fresh clone or in CI, `offline` decides whether the
+/// part gets its star.
+fn cell(result: Option<&SolveResult>, offline: impl FnOnce() -> bool) -> String {
+    match result {
+        Some(result) if result.status == Status::Solved => {
+            format!("⭐ {:.2?}", runtime(result))
+        }
+        Some(result) if result.status != Status::NoInput => " ".to_owned(),
+        _ if offline() => "⭐".to_owned(),
+        _ => " ".to_owned(),
+    }
+}
+
+/// Whether a registered part counts as solved without its input: it has a recorded answer, or
+/// its solver finds an answer for the day's example.
+fn solved_offline(day: &days::Day, part: u8) -> bool {
+    let recorded = Answers::read(day.year, day.day, None).unwrap_or_default();
+    if recorded.get(part).is_some() {
+        return true;
+    }
+    let Ok(example) = crate::input::read_path(&crate::file_path("examples", day.year, day.day))
+    else {
+        return false;
+    };
+    let example = day.prepare_input(example);
+    let solver = if part == 1 {
+        day.part_one
+    } else {
+        day.part_two
+    };
+    matches!(solver(&example).0, Ok(Some(_)))
+}
+
+/// Renders one table per year of the registry, with a row for every day that has a solution.
+///
+/// `offline` is passed in rather than looked up, so the output only depends on the arguments.
+pub fn render(
+    days: &[days::Day],
+    results: &[SolveResult],
+    offline: impl Fn(&days::Day, u8) -> bool,
+) -> String {
+    let mut section = String::new();
+    let mut years: Vec<i32> = days.iter().map(|day| day.year).collect();
+    years.dedup();
+
+    for year in years {
+        writeln!(section, "## {} Results\n", year).unwrap();
+        writeln!(section, "| Day | Part 1 | Part 2 |").unwrap();
+        writeln!(section, "| :---: | :---: | :---: |").unwrap();
+
+        let mut total = Duration::ZERO;
+        for day in days.iter().filter(|day| day.year == year) {
+            let cells: Vec<String> = [1, 2]
+                .into_iter()
+                .map(|part| {
+                    let result = results.iter().find(|result| {
+                        result.year == day.year && result.day == day.day && result.part == part
+                    });
+                    if let Some(result) = result.filter(|result| result.status == Status::Solved) {
+                        total += runtime(result);
+                    }
+                    cell(result, || offline(day, part))
+                })
+                .collect();
+
+            writeln!(
+                section,
+                "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |",
+                day.day, day.year, day.day, cells[0], cells[1]
+            )
+            .unwrap();
+        }
+
+        writeln!(section).unwrap();
+        if !total.is_zero() {
+            writeln!(section, "**Total runtime:** {:.2?}\n", total).unwrap();
+        }
+    }
+
+    section
+}
+
+/// Replaces everything between the markers with `section`, leaving the rest of the README as it is.
+pub fn replace_section(readme: &str, section: &str) -> Result<String, String> {
+    let missing = || {
+        format!(
+            "the README needs a section between \"{}\" and \"{}\"",
+            START_MARKER, END_MARKER
+        )
+    };
+    let start = readme.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = start + readme[start..].find(END_MARKER).ok_or_else(missing)?;
+
+    Ok(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        section,
+        &readme[end..]
+    ))
+}
+
+/// The stars of a section, without the runtimes, which differ a little on every run.
+fn stars(section: &str) -> String {
+    section
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with("**Total runtime:**"))
+        .map(|line| {
+            let cells: Vec<&str> = line
+                .split('|')
+                .map(|cell| if cell.contains('⭐') { "⭐" } else { cell })
+                .collect();
+            cells.join("|")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Regenerates the stats section of the README. Returns whether the file changed.
+///
+/// The section is only rewritten when a star changed, or when `refresh_runtimes` asks for the new
+/// runtimes, so that running it again does not produce a diff.
+pub fn update(results: &[SolveResult], refresh_runtimes: bool) -> io::Result<bool> {
+    let path = path();
+    let readme = fs::read_to_string(&path)?;
+    let section = render(days::DAYS, results, solved_offline);
+    let updated = replace_section(&readme, &section)
+        .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+
+    if updated == readme || (!refresh_runtimes && stars(&updated) == stars(&readme)) {
+        return Ok(false);
+    }
+    fs::write(path, updated)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    fn solver(_: &str) -> Answer {
+        Answer(Ok(None))
+    }
+
+    fn day(year: i32, day: u8) -> days::Day {
+        days::Day {
+            year,
+            day,
+            part_one: solver,
+            part_two: solver,
+            normalize_input: true,
+            draw: None,
+            animate: None,
+        }
+    }
+
+    fn offline(day: &days::Day, _: u8) -> bool {
+        day.day == 2
+    }
+
+    #[test]
+    fn test_render() {
+        let days = [day(2022, 1), day(2022, 2), day(2022, 25)];
+        let results = [
+            SolveResult::solved(2022, 1, 1, "24000".to_owned(), Duration::from_micros(40)),
+            SolveResult::solved(2022, 1, 2, "45000".to_owned(), Duration::from_micros(60)),
+            SolveResult::no_input(2022, 2, 1),
+            SolveResult::no_input(2022, 2, 2),
+            SolveResult::solved(2022, 25, 1, "2=-1=0".to_owned(), Duration::from_micros(5)),
+            SolveResult::unsolved(2022, 25, 2, Duration::ZERO),
+        ];
+
+        assert_eq!(
+            render(&days, &results, offline),
+            concat!(
+                "## 2022 Results\n\n",
+                "| Day | Part 1 | Part 2 |\n",
+                "| :---: | :---: | :---: |\n",
+                "| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ 40.00µs | ⭐ 60.00µs |\n",
+                "| [Day 2](https://adventofcode.com/2022/day/2) | ⭐ | ⭐ |\n",
+                "| [Day 25](https://adventofcode.com/2022/day/25) | ⭐ 5.00µs |   |\n",
+                "\n**Total runtime:** 105.00µs\n\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_stars() {
+        let days = [day(2022, 1), day(2022, 25)];
+        let fast = [
+            SolveResult::solved(2022, 1, 1, "1".to_owned(), Duration::from_micros(40)),
+            SolveResult::solved(2022, 25, 1, "2".to_owned(), Duration::from_micros(5)),
+        ];
+        let slow = [
+            SolveResult::solved(2022, 1, 1, "1".to_owned(), Duration::from_micros(41)),
+            SolveResult::no_input(2022, 25, 1),
+        ];
+        let no_fallback = |_: &days::Day, _: u8| false;
+        let fast = render(&days, &fast, no_fallback);
+        let recorded = |day: &days::Day, part: u8| (day.day, part) == (25, 1);
+        assert_eq!(stars(&fast), stars(&render(&days, &slow, recorded)));
+        assert_ne!(stars(&fast), stars(&render(&days, &slow, no_fallback)));
+    }
+
+    #[test]
+    fn test_solved_offline() {
+        let day = days::get(2022, 25).unwrap();
+        assert!(solved_offline(day, 1));
+        assert!(!solved_offline(day, 2));
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# AoC\n{}\nold\n{}\nmore\n", START_MARKER, END_MARKER);
+        let updated = replace_section(&readme, "new\n").unwrap();
+        assert_eq!(
+            updated,
+            format!("# AoC\n{}\nnew\n{}\nmore\n", START_MARKER, END_MARKER)
+        );
+        assert_eq!(replace_section(&updated, "new\n").unwrap(), updated);
+
+        assert!(replace_section("# AoC\n", "new\n").is_err());
+        assert!(replace_section(&format!("{}\n", START_MARKER), "new\n").is_err());
+    }
+}
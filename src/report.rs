@@ -0,0 +1,224 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::SolveResult;
+
+/// Output format of the all-days runner.
+///
+/// The machine-readable formats have one record per part with the fields
+/// `day`, `part`, `answer`, `elapsed_ns`, `status`, `input_set` and `year`, in that order.
+/// `input_set` is empty for the default input. Fields added later go at the end, so readers
+/// of the original columns keep working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Splits `to_csv` output into its records. Unlike `str::lines`, this keeps the line breaks of a
+/// quoted field, e.g. an answer drawn over several lines, inside its record.
+pub fn csv_records(csv: &str) -> Vec<&str> {
+    let mut records = vec![];
+    let mut start = 0;
+    let mut quoted = false;
+    for (i, c) in csv.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '\n' if !quoted => {
+                records.push(csv[start..i].trim_end_matches('\r'));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < csv.len() {
+        records.push(&csv[start..]);
+    }
+    records
+}
+
+/// Splits one record of `to_csv` output into its fields, undoing the quoting of `csv_field`.
+pub fn parse_csv_record(line: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            (c, _) => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+pub fn to_json(results: &[SolveResult]) -> String {
+    let records: Vec<String> = results
+        .iter()
+        .map(|result| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ns\": {}, \"status\": \"{}\", \"input_set\": {}, \"year\": {}}}",
+                result.day,
+                result.part,
+                result.answer.as_deref().map_or("null".to_owned(), json_string),
+                result.elapsed.as_nanos(),
+                result.status,
+                result.input_set.as_deref().map_or("null".to_owned(), json_string),
+                result.year
+            )
+        })
+        .collect();
+
+    if records.is_empty() {
+        "[]".to_owned()
+    } else {
+        format!("[\n{}\n]", records.join(",\n"))
+    }
+}
+
+/// The first line of `to_csv` output.
+pub const CSV_HEADER: &str = "day,part,answer,elapsed_ns,status,input_set,year";
+
+pub fn to_csv(results: &[SolveResult]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for result in results {
+        writeln!(
+            csv,
+            "{},{},{},{},{},{},{}",
+            result.day,
+            result.part,
+            result.answer.as_deref().map_or(String::new(), csv_field),
+            result.elapsed.as_nanos(),
+            result.status,
+            result.input_set.as_deref().map_or(String::new(), csv_field),
+            result.year
+        )
+        .unwrap();
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn results() -> Vec<SolveResult> {
+        let mut edge_case = SolveResult::unsolved(2022, 25, 2, Duration::ZERO);
+        edge_case.input_set = Some("edge".to_owned());
+        vec![
+            SolveResult::solved(2022, 5, 1, "CMZ".to_owned(), Duration::from_nanos(1500)),
+            SolveResult::solved(2022, 5, 2, "a,\"b\"".to_owned(), Duration::from_micros(2)),
+            edge_case,
+        ]
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(&results()),
+            concat!(
+                "[\n",
+                "  {\"day\": 5, \"part\": 1, \"answer\": \"CMZ\", \"elapsed_ns\": 1500, \"status\": \"solved\", \"input_set\": null, \"year\": 2022},\n",
+                "  {\"day\": 5, \"part\": 2, \"answer\": \"a,\\\"b\\\"\", \"elapsed_ns\": 2000, \"status\": \"solved\", \"input_set\": null, \"year\": 2022},\n",
+                "  {\"day\": 25, \"part\": 2, \"answer\": null, \"elapsed_ns\": 0, \"status\": \"unsolved\", \"input_set\": \"edge\", \"year\": 2022}\n",
+                "]"
+            )
+        );
+        assert_eq!(to_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            concat!(
+                "day,part,answer,elapsed_ns,status,input_set,year\n",
+                "5,1,CMZ,1500,solved,,2022\n",
+                "5,2,\"a,\"\"b\"\"\",2000,solved,,2022\n",
+                "25,2,,0,unsolved,edge,2022\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_record() {
+        let csv = to_csv(&results());
+        let records: Vec<Vec<String>> = csv.lines().map(parse_csv_record).collect();
+        assert_eq!(records[0].len(), 7);
+        assert_eq!(records[2][2], "a,\"b\"");
+        assert_eq!(records[3][2], "");
+        assert_eq!(records[3][5], "edge");
+    }
+
+    #[test]
+    fn test_csv_records() {
+        let screen = SolveResult::solved(2022, 10, 2, "#.\n.#".to_owned(), Duration::ZERO);
+        let csv = to_csv(&[screen]);
+        let records = csv_records(&csv);
+        assert_eq!(records, [CSV_HEADER, "10,2,\"#.\n.#\",0,solved,,2022"]);
+        assert_eq!(parse_csv_record(records[1])[2], "#.\n.#");
+    }
+}
@@ -0,0 +1,262 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+use crate::bench::Benchmark;
+use crate::Error;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The answer differs from the one recorded in `src/YYYY/answers`.
+    Mismatch,
+    /// The solver returned an `Error`.
+    Error,
+    /// The day's input file could not be read, so the solver was not run.
+    NoInput,
+    /// The solver was stopped after running longer than the runner's `--timeout`.
+    Timeout,
+    /// The solver was stopped after using more memory than the runner's `--memory-limit`.
+    Oom,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Mismatch => write!(f, "mismatch"),
+            Status::Error => write!(f, "error"),
+            Status::NoInput => write!(f, "no_input"),
+            Status::Timeout => write!(f, "timeout"),
+            Status::Oom => write!(f, "oom"),
+        }
+    }
+}
+
+/// Return types accepted from `part_one` / `part_two`: `Option<T>` and `Result<T, Error>`.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Option<String>, Error>;
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, Error> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+impl<T: Display> IntoAnswer for Result<T, Error> {
+    fn into_answer(self) -> Result<Option<String>, Error> {
+        self.map(|answer| Some(answer.to_string()))
+    }
+}
+
+/// An answer that was already converted with `IntoAnswer`, as returned by the solvers in `days::DAYS`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer(pub Result<Option<String>, Error>);
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Result<Option<String>, Error> {
+        self.0
+    }
+}
+
+/// The outcome of running one part of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveResult {
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// The recorded answer this result was verified against, if any.
+    pub expected: Option<String>,
+    pub elapsed: Duration,
+    pub status: Status,
+    /// The error returned by the solver, if it failed.
+    pub error: Option<Error>,
+    /// Timing statistics when the part was run with `--bench`.
+    pub benchmark: Option<Benchmark>,
+    /// The named input the part was run against, `None` for the default input.
+    pub input_set: Option<String>,
+}
+
+impl SolveResult {
+    pub fn solved(year: i32, day: u8, part: u8, answer: String, elapsed: Duration) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: Some(answer),
+            expected: None,
+            elapsed,
+            status: Status::Solved,
+            error: None,
+            benchmark: None,
+            input_set: None,
+        }
+    }
+
+    pub fn unsolved(year: i32, day: u8, part: u8, elapsed: Duration) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            expected: None,
+            elapsed,
+            status: Status::Unsolved,
+            error: None,
+            benchmark: None,
+            input_set: None,
+        }
+    }
+
+    pub fn failed(year: i32, day: u8, part: u8, error: Error, elapsed: Duration) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            expected: None,
+            elapsed,
+            status: Status::Error,
+            error: Some(error),
+            benchmark: None,
+            input_set: None,
+        }
+    }
+
+    /// Builds the result of a part from the converted return value of its solver.
+    pub fn from_answer(
+        year: i32,
+        day: u8,
+        part: u8,
+        answer: Result<Option<String>, Error>,
+        elapsed: Duration,
+    ) -> Self {
+        match answer {
+            Ok(Some(answer)) => Self::solved(year, day, part, answer, elapsed),
+            Ok(None) => Self::unsolved(year, day, part, elapsed),
+            Err(e) => Self::failed(year, day, part, e, elapsed),
+        }
+    }
+
+    /// A part that was stopped by one of the runner's limits, `Status::Timeout` or `Status::Oom`.
+    pub fn limited(year: i32, day: u8, part: u8, status: Status, elapsed: Duration) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            expected: None,
+            elapsed,
+            status,
+            error: None,
+            benchmark: None,
+            input_set: None,
+        }
+    }
+
+    pub fn no_input(year: i32, day: u8, part: u8) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            expected: None,
+            elapsed: Duration::ZERO,
+            status: Status::NoInput,
+            error: None,
+            benchmark: None,
+            input_set: None,
+        }
+    }
+}
+
+impl Display for SolveResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET)?;
+        match (&self.answer, &self.benchmark) {
+            (Some(answer), Some(benchmark)) => write!(f, "{} {}", answer, benchmark)?,
+            (Some(answer), None) => write!(
+                f,
+                "{} {}(elapsed: {:.2?}){}",
+                answer, ANSI_ITALIC, self.elapsed, ANSI_RESET
+            )?,
+            (None, _) => match (&self.error, self.status) {
+                (Some(error), _) => write!(f, "{}error:{} {}", ANSI_BOLD, ANSI_RESET, error)?,
+                (None, Status::Timeout | Status::Oom) => write!(
+                    f,
+                    "{}{}{} {}(stopped after {:.2?}){}",
+                    ANSI_BOLD,
+                    self.status.to_string().to_uppercase(),
+                    ANSI_RESET,
+                    ANSI_ITALIC,
+                    self.elapsed,
+                    ANSI_RESET
+                )?,
+                (None, _) => write!(f, "not solved.")?,
+            },
+        }
+        // Anything but a solved part is flagged when an answer was recorded for it.
+        if let Some(expected) = self
+            .expected
+            .as_ref()
+            .filter(|_| self.status != Status::Solved)
+        {
+            write!(
+                f,
+                "\n{}mismatch:{} expected {}",
+                ANSI_BOLD, ANSI_RESET, expected
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let result = SolveResult::solved(2022, 1, 2, "45000".to_owned(), Duration::from_micros(70));
+        assert_eq!(
+            result.to_string(),
+            format!(
+                "🎄 {}Part 2{} 🎄\n45000 {}(elapsed: 70.00µs){}",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+            )
+        );
+
+        let mut result = SolveResult::solved(2022, 1, 2, "44999".to_owned(), Duration::ZERO);
+        result.status = Status::Mismatch;
+        result.expected = Some("45000".to_owned());
+        assert!(result.to_string().ends_with(&format!(
+            "\n{}mismatch:{} expected 45000",
+            ANSI_BOLD, ANSI_RESET
+        )));
+
+        let result =
+            SolveResult::failed(2022, 1, 1, Error::invalid_state("no elves"), Duration::ZERO);
+        assert!(result.to_string().ends_with(&format!(
+            "{}error:{} invalid puzzle state: no elves",
+            ANSI_BOLD, ANSI_RESET
+        )));
+
+        let result = SolveResult::unsolved(2022, 1, 1, Duration::ZERO);
+        assert_eq!(result.status, Status::Unsolved);
+        assert!(result.to_string().ends_with("not solved."));
+
+        let result = SolveResult::limited(2022, 1, 1, Status::Timeout, Duration::from_millis(1500));
+        assert!(result.to_string().ends_with(&format!(
+            "{}TIMEOUT{} {}(stopped after 1.50s){}",
+            ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+        )));
+    }
+}
@@ -0,0 +1,314 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The costs weighted searches add up. `Default` is taken as zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// What a search found out: the cost of reaching every node it settled, how it got there, and the
+/// first goal it reached.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The first goal that was reached, which is the closest one to any of the starts.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of the cheapest path from any of the starts to `node`.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// The costs of all nodes that were reached, i.e. a distance map.
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// The cheapest path to `node`, starting with the start it came from and ending with `node`.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal, if one was reached.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth-first search from all of `starts` at once, where every step costs 1.
+///
+/// Stops as soon as a node matching `is_goal` is reached. Pass `|_| false` to visit every
+/// reachable node, e.g. for a flood fill or a full distance map.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if paths.contains(&start) {
+            continue;
+        }
+        paths.costs.insert(start.clone(), 0);
+        queue.push_back(start);
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let cost = paths.costs[&node] + 1;
+        for neighbor in neighbors(&node) {
+            if paths.contains(&neighbor) {
+                continue;
+            }
+            paths.costs.insert(neighbor.clone(), cost);
+            paths.parents.insert(neighbor.clone(), node.clone());
+            queue.push_back(neighbor);
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm from all of `starts` at once. `cost` is the cost of the step between two
+/// neighbours and must not be negative.
+///
+/// Stops as soon as a node matching `is_goal` is reached, like `bfs`.
+pub fn dijkstra<N, I, C>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    cost: impl FnMut(&N, &N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    C: Cost,
+{
+    astar(starts, neighbors, cost, |_| C::default(), is_goal)
+}
+
+/// A* search from all of `starts` at once, expanding the nodes whose cost plus `heuristic` is
+/// smallest first.
+///
+/// The heuristic estimates the remaining cost to the closest goal. It must never overestimate,
+/// and must not drop by more than the cost of a step, e.g. the manhattan distance on a map where
+/// every step costs at least 1. Otherwise the paths found are not guaranteed to be the cheapest.
+pub fn astar<N, I, C>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut cost: impl FnMut(&N, &N) -> C,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    C: Cost,
+{
+    let mut paths = Paths::new();
+    // The cheapest cost found so far for nodes that are queued but not settled yet.
+    let mut best: HashMap<N, C> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        best.insert(start.clone(), C::default());
+        queue.push(Entry {
+            priority: heuristic(&start),
+            cost: C::default(),
+            node: start,
+            parent: None,
+        });
+    }
+
+    while let Some(entry) = queue.pop() {
+        if paths.contains(&entry.node) {
+            continue;
+        }
+        paths.costs.insert(entry.node.clone(), entry.cost);
+        if let Some(parent) = entry.parent {
+            paths.parents.insert(entry.node.clone(), parent);
+        }
+        if is_goal(&entry.node) {
+            paths.goal = Some(entry.node);
+            break;
+        }
+
+        for neighbor in neighbors(&entry.node) {
+            if paths.contains(&neighbor) {
+                continue;
+            }
+            let neighbor_cost = entry.cost + cost(&entry.node, &neighbor);
+            if best
+                .get(&neighbor)
+                .is_some_and(|best| *best <= neighbor_cost)
+            {
+                continue;
+            }
+            best.insert(neighbor.clone(), neighbor_cost);
+            queue.push(Entry {
+                priority: neighbor_cost + heuristic(&neighbor),
+                cost: neighbor_cost,
+                node: neighbor,
+                parent: Some(entry.node.clone()),
+            });
+        }
+    }
+
+    paths
+}
+
+/// A queued node, ordered so that `BinaryHeap` pops the lowest priority first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+    parent: Option<N>,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equal priorities, the node that is further along is expanded first.
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::point::Point;
+
+    const MAZE: &str = "\
+S.#.....
+..#.##.#
+..#..#..
+....#..E
+";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Some).unwrap()
+    }
+
+    fn open(grid: &Grid<char>) -> impl FnMut(&Point) -> Vec<Point> + '_ {
+        |point| {
+            grid.neighbors4(*point)
+                .filter(|neighbor| grid[*neighbor] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let start = grid.position(|c| *c == 'S').unwrap();
+        let end = grid.position(|c| *c == 'E').unwrap();
+
+        let paths = bfs([start], open(&grid), |point| *point == end);
+        assert_eq!(paths.goal(), Some(&end));
+        assert_eq!(paths.cost(&end), Some(16));
+
+        let path = paths.goal_path().unwrap();
+        assert_eq!(path.len(), 17);
+        assert_eq!((path[0], path[16]), (start, end));
+        assert!(path
+            .windows(2)
+            .all(|step| step[0].manhattan_distance(&step[1]) == 1 && grid[step[1]] != '#'));
+
+        // Without a goal, everything reachable is visited.
+        let paths = bfs([start], open(&grid), |_| false);
+        assert_eq!(paths.goal(), None);
+        assert_eq!(paths.costs().len(), 24);
+        assert!(!paths.contains(&Point::new(2, 0)));
+        assert_eq!(paths.path(&Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let grid = maze();
+        let end = grid.position(|c| *c == 'E').unwrap();
+        let starts = [Point::new(0, 0), Point::new(6, 0)];
+
+        let paths = bfs(starts, open(&grid), |point| *point == end);
+        assert_eq!(paths.cost(&end), Some(4));
+        assert_eq!(paths.goal_path().unwrap()[0], Point::new(6, 0));
+        assert_eq!(paths.cost(&Point::new(0, 0)), Some(0));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Walking on '.' costs 1 and wading through '~' costs 9.
+        let grid = Grid::parse("...\n~~.\n...\n", Some).unwrap();
+        let start = Point::new(0, 0);
+        let end = Point::new(0, 2);
+        let neighbors = |point: &Point| grid.neighbors4(*point).collect::<Vec<_>>();
+        let cost = |_: &Point, to: &Point| if grid[*to] == '~' { 9 } else { 1 };
+
+        let paths = dijkstra([start], neighbors, cost, |point| *point == end);
+        assert_eq!(paths.cost(&end), Some(6));
+        assert_eq!(paths.goal_path().unwrap().len(), 7);
+        // The full distance map, without a goal.
+        let all = dijkstra([start], neighbors, cost, |_| false);
+        assert_eq!(all.costs().len(), 9);
+        assert_eq!(all.cost(&Point::new(1, 1)), Some(10));
+
+        let heuristic = |point: &Point| point.manhattan_distance(&end);
+        let paths = astar([start], neighbors, cost, heuristic, |point| *point == end);
+        assert_eq!(paths.cost(&end), Some(6));
+        assert!(paths.costs().len() < 9);
+    }
+}
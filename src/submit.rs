@@ -0,0 +1,480 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::answers::{escape, unescape};
+use crate::client::{Client, ClientError};
+
+/// Folder below `src/YYYY` that logs every submitted answer, one `NN.txt` file per day.
+pub const ATTEMPTS_FOLDER: &str = "attempts";
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// The answer was not judged because the last submission was too recent.
+    Wait,
+    /// The part was already solved, e.g. through the website.
+    AlreadySolved,
+    /// The response could not be interpreted.
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer was judged to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too_high"),
+            Verdict::TooLow => write!(f, "too_low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::Wait => write!(f, "wait"),
+            Verdict::AlreadySolved => write!(f, "already_solved"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "wait" => Ok(Verdict::Wait),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The interpreted answer page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// Seconds until the site accepts the next answer, if it said so.
+    pub wait: Option<u64>,
+    /// The text of the response, without markup.
+    pub message: String,
+}
+
+/// Parses a duration like `one minute`, `5 minutes`, `4m 32s` or `32s` at the start of `text`.
+fn parse_duration(text: &str) -> Option<u64> {
+    let mut words = text.split_whitespace();
+    let first = words.next()?;
+
+    let count = match first {
+        "one" | "a" => Some(1),
+        _ => first.parse().ok(),
+    };
+    if let Some(count) = count {
+        return match words.next()? {
+            unit if unit.starts_with("minute") => Some(count * 60),
+            unit if unit.starts_with("second") => Some(count),
+            _ => None,
+        };
+    }
+
+    let mut seconds = 0;
+    for word in text.split_whitespace() {
+        let value = |suffix| word.strip_suffix(suffix)?.parse::<u64>().ok();
+        match (value('m'), value('s')) {
+            (Some(minutes), _) => seconds += minutes * 60,
+            (_, Some(secs)) => seconds += secs,
+            _ => break,
+        }
+    }
+    (seconds > 0).then_some(seconds)
+}
+
+/// Interprets the page returned for a submitted answer.
+pub fn parse_response(html: &str) -> Response {
+    let article = crate::puzzle::articles(html)
+        .first()
+        .copied()
+        .unwrap_or(html);
+    let message = crate::puzzle::to_markdown(&format!("<article>{}</article>", article))
+        .replace(['*', '`'], "")
+        .trim()
+        .to_owned();
+    let text = message.to_lowercase();
+
+    let verdict = if text.contains("that's the right answer") {
+        Verdict::Correct
+    } else if text.contains("answer too recently") {
+        Verdict::Wait
+    } else if text.contains("not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if text.contains("already complete it") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    };
+
+    let wait = [
+        ("you have ", " left to wait"),
+        ("please wait ", " before trying again"),
+    ]
+    .iter()
+    .find_map(|(start, end)| {
+        let to = text.find(end)?;
+        let from = text[..to].rfind(start)? + start.len();
+        parse_duration(&text[from..to])
+    });
+
+    Response {
+        verdict,
+        wait,
+        message,
+    }
+}
+
+/// A submitted answer, as logged in `src/YYYY/attempts/NN.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Unix time of the submission.
+    pub time: u64,
+    pub part: u8,
+    pub verdict: Verdict,
+    /// Unix time from which the site accepts answers again.
+    pub retry_at: Option<u64>,
+    pub answer: String,
+}
+
+impl Attempt {
+    fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {} {}",
+            self.time,
+            self.part,
+            self.verdict,
+            self.retry_at.map_or("-".to_owned(), |t| t.to_string()),
+            escape(&self.answer)
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(5, ' ');
+        Some(Self {
+            time: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            verdict: fields.next()?.parse().ok()?,
+            retry_at: match fields.next()? {
+                "-" => None,
+                t => Some(t.parse().ok()?),
+            },
+            answer: unescape(fields.next()?),
+        })
+    }
+}
+
+/// The log of every answer submitted for a day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Attempts {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    pub fn read(year: i32, day: u8) -> io::Result<Self> {
+        match crate::try_read_file(ATTEMPTS_FOLDER, year, day) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        Self {
+            attempts: contents.lines().filter_map(Attempt::from_line).collect(),
+        }
+    }
+
+    /// Appends an attempt to the log of a day.
+    pub fn append(year: i32, day: u8, attempt: &Attempt) -> io::Result<()> {
+        let path = crate::file_path(ATTEMPTS_FOLDER, year, day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", attempt.to_line())
+    }
+
+    /// Explains why `answer` must not be submitted for `part` at unix time `now`, if it must not.
+    ///
+    /// An answer is refused if it spans several lines, the part is already solved, the site asked
+    /// to wait, the same answer was judged wrong before, or an earlier too-high / too-low verdict
+    /// rules it out.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        if answer.contains('\n') {
+            return Err(
+                "the answer spans several lines, read the letters off it and submit them on the website"
+                    .to_owned(),
+            );
+        }
+
+        if let Some(attempt) = self
+            .attempts
+            .iter()
+            .find(|a| a.part == part && a.verdict == Verdict::Correct)
+        {
+            return Err(format!(
+                "part {} is already solved with {}",
+                part, attempt.answer
+            ));
+        }
+
+        if let Some(retry_at) = self.attempts.last().and_then(|a| a.retry_at) {
+            if retry_at > now {
+                return Err(format!(
+                    "the site asked to wait, try again in {}s",
+                    retry_at - now
+                ));
+            }
+        }
+
+        let wrong = self
+            .attempts
+            .iter()
+            .filter(|a| a.part == part && a.verdict.is_wrong());
+
+        if let Some(attempt) = wrong.clone().find(|a| a.answer == answer) {
+            return Err(format!(
+                "{} was already submitted and is {}",
+                answer,
+                attempt.verdict.to_string().replace('_', " ")
+            ));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            for attempt in wrong {
+                let Ok(bound) = attempt.answer.parse::<i128>() else {
+                    continue;
+                };
+                match attempt.verdict {
+                    Verdict::TooHigh if value >= bound => {
+                        return Err(format!("{} is too high, {} already was", answer, bound))
+                    }
+                    Verdict::TooLow if value <= bound => {
+                        return Err(format!("{} is too low, {} already was", answer, bound))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The attempt log rules the answer out, so it was not sent.
+    Refused(String),
+    Client(ClientError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(reason) => write!(f, "not submitted: {}", reason),
+            SubmitError::Client(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+pub fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Checks an answer against the attempt log, posts it, and returns the attempt to log.
+pub fn submit(
+    client: &Client,
+    attempts: &Attempts,
+    year: i32,
+    day: u8,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Result<(Response, Attempt), SubmitError> {
+    attempts
+        .check(part, answer, now)
+        .map_err(SubmitError::Refused)?;
+
+    let html = client
+        .post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+        .map_err(SubmitError::Client)?;
+
+    let response = parse_response(&html);
+    let attempt = Attempt {
+        time: now,
+        part,
+        verdict: response.verdict,
+        retry_at: response.wait.map(|wait| now + wait),
+        answer: answer.to_owned(),
+    };
+    Ok((response, attempt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub;
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main>";
+
+    #[test]
+    fn test_parse_response() {
+        let response = parse_response(TOO_HIGH);
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(response.wait, Some(60));
+        assert!(response.message.starts_with("That's not the right answer"));
+
+        let response = parse_response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. </p></article>");
+        assert_eq!(response.verdict, Verdict::Wait);
+        assert_eq!(response.wait, Some(272));
+
+        let response = parse_response("<article><p>That's not the right answer.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.</p></article>");
+        assert_eq!(response.verdict, Verdict::Incorrect);
+        assert_eq!(response.wait, Some(300));
+
+        let response = parse_response("<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.</p></article>");
+        assert_eq!(response.verdict, Verdict::Correct);
+        assert_eq!(response.wait, None);
+
+        let response = parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>");
+        assert_eq!(response.verdict, Verdict::AlreadySolved);
+    }
+
+    fn attempt(part: u8, verdict: Verdict, answer: &str) -> Attempt {
+        Attempt {
+            time: 100,
+            part,
+            verdict,
+            retry_at: None,
+            answer: answer.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut attempts = Attempts {
+            attempts: vec![
+                attempt(1, Verdict::TooHigh, "500"),
+                attempt(1, Verdict::TooLow, "100"),
+                attempt(1, Verdict::Incorrect, "300"),
+                attempt(2, Verdict::Correct, "42"),
+            ],
+        };
+
+        assert_eq!(attempts.check(1, "200", 200), Ok(()));
+        assert_eq!(
+            attempts.check(1, "300", 200),
+            Err("300 was already submitted and is incorrect".to_owned())
+        );
+        assert_eq!(
+            attempts.check(1, "600", 200),
+            Err("600 is too high, 500 already was".to_owned())
+        );
+        assert_eq!(
+            attempts.check(1, "100", 200),
+            Err("100 was already submitted and is too low".to_owned())
+        );
+        assert_eq!(
+            attempts.check(2, "43", 200),
+            Err("part 2 is already solved with 42".to_owned())
+        );
+
+        attempts.attempts.push(Attempt {
+            retry_at: Some(260),
+            ..attempt(1, Verdict::Wait, "200")
+        });
+        assert_eq!(
+            attempts.check(1, "200", 200),
+            Err("the site asked to wait, try again in 60s".to_owned())
+        );
+        assert_eq!(attempts.check(1, "200", 260), Ok(()));
+        assert!(attempts.check(1, "#..#\n#..#", 260).is_err());
+    }
+
+    #[test]
+    fn test_attempt_line() {
+        let attempt = Attempt {
+            retry_at: Some(160),
+            ..attempt(1, Verdict::TooLow, "ABC DEF")
+        };
+        assert_eq!(attempt.to_line(), "100 1 too_low 160 ABC DEF");
+        assert_eq!(
+            Attempt::from_line(&attempt.to_line()),
+            Some(attempt.clone())
+        );
+        assert_eq!(Attempt::from_line("100 1 maybe - 5"), None);
+
+        let screen = Attempt {
+            answer: "#..#\n#..#".to_owned(),
+            ..attempt
+        };
+        assert_eq!(screen.to_line(), "100 1 too_low 160 #..#\\n#..#");
+        let attempts = Attempts::parse(&format!("{}\n", screen.to_line()));
+        assert_eq!(attempts.attempts, [screen]);
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = stub::serve(vec![(200, TOO_HIGH)]);
+        let client = Client::new("abc123").with_base_url(base_url);
+        let attempts = Attempts::default();
+
+        let (response, attempt) = submit(&client, &attempts, 2022, 1, 1, "500", 1_000).unwrap();
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(attempt.retry_at, Some(1_060));
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2022/day/1/answer HTTP/1.1");
+        assert_eq!(request.body, "level=1&answer=500");
+
+        // Refused answers never reach the server, which would not answer a second request.
+        let attempts = Attempts {
+            attempts: vec![attempt],
+        };
+        assert!(matches!(
+            submit(&client, &attempts, 2022, 1, 1, "501", 2_000),
+            Err(SubmitError::Refused(_))
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::DayArgs;
+
+/// Frames per second of `--animate` without `--fps`.
+const DEFAULT_FPS: f64 = 30_f64;
+
+/// The largest part of a scene shown without `--viewport`, so huge maps do not flood the terminal.
+const MAX_WIDTH: usize = 200;
+const MAX_HEIGHT: usize = 60;
+
+/// A rectangle of cells to show, `width` cells to the right and `height` cells down from `origin`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub origin: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    pub fn new(origin: Point, width: usize, height: usize) -> Self {
+        Self {
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// The smallest viewport that contains all `points`, `None` if there are none.
+    pub fn around(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        });
+        Some(Self::new(
+            min,
+            min.x_distance(&max) as usize + 1,
+            min.y_distance(&max) as usize + 1,
+        ))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&(point.x as i64 - self.origin.x as i64))
+            && (0..self.height as i64).contains(&(point.y as i64 - self.origin.y as i64))
+    }
+
+    /// The rows of the viewport from top to bottom, each from left to right.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Point>> {
+        let Viewport {
+            origin,
+            width,
+            height,
+        } = *self;
+        (0..height as i32)
+            .map(move |y| (0..width as i32).map(move |x| Point::new(origin.x + x, origin.y + y)))
+    }
+}
+
+/// Parses `x,y,width,height`, e.g. `490,0,20,12`.
+impl FromStr for Viewport {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid viewport \"{}\", expected x,y,width,height", value);
+        let parts: Vec<&str> = value.split(',').map(str::trim).collect();
+        let [x, y, width, height] = parts[..] else {
+            return Err(invalid());
+        };
+        Ok(Self::new(
+            Point::new(
+                x.parse().map_err(|_| invalid())?,
+                y.parse().map_err(|_| invalid())?,
+            ),
+            width.parse().map_err(|_| invalid())?,
+            height.parse().map_err(|_| invalid())?,
+        ))
+    }
+}
+
+/// The state of a simulation, drawn one character per cell.
+pub trait Scene {
+    /// The character at `point`.
+    fn cell(&self, point: Point) -> char;
+
+    /// The part of the plane that is worth showing when no viewport was chosen.
+    fn bounds(&self) -> Viewport;
+
+    /// Draws the cells in `viewport`, one line per row.
+    fn frame(&self, viewport: &Viewport) -> String {
+        let rows: Vec<String> = viewport
+            .rows()
+            .map(|row| row.map(|point| self.cell(point)).collect())
+            .collect();
+        rows.join("\n")
+    }
+}
+
+impl Scene for Grid<char> {
+    fn cell(&self, point: Point) -> char {
+        self.get(point).copied().unwrap_or(' ')
+    }
+
+    fn bounds(&self) -> Viewport {
+        Viewport::new(Point::default(), self.width(), self.height())
+    }
+}
+
+#[derive(Debug)]
+enum Output {
+    Off,
+    Terminal,
+    Capture(Vec<String>),
+}
+
+/// Shows the frames of a simulation: in the terminal with `--animate`, collected in memory for
+/// tests, or not at all.
+#[derive(Debug)]
+pub struct Animator {
+    output: Output,
+    /// Frames per second in the terminal. 0 shows them as fast as possible.
+    fps: f64,
+    viewport: Option<Viewport>,
+    last_frame: Option<Instant>,
+}
+
+impl Animator {
+    /// Ignores every frame, which costs nothing but the check.
+    pub fn off() -> Self {
+        Self {
+            output: Output::Off,
+            fps: DEFAULT_FPS,
+            viewport: None,
+            last_frame: None,
+        }
+    }
+
+    /// Draws every frame over the previous one, `fps` frames per second.
+    pub fn terminal(fps: f64) -> Self {
+        Self {
+            output: Output::Terminal,
+            fps,
+            ..Self::off()
+        }
+    }
+
+    /// Keeps the frames as strings instead of showing them, see `frames`.
+    pub fn capture() -> Self {
+        Self {
+            output: Output::Capture(vec![]),
+            ..Self::off()
+        }
+    }
+
+    /// Shows this part of every scene instead of its bounds.
+    pub fn with_viewport(self, viewport: Viewport) -> Self {
+        Self {
+            viewport: Some(viewport),
+            ..self
+        }
+    }
+
+    /// A terminal animator with `--animate`, configured by `--fps` and `--viewport`.
+    pub fn from_args(args: &DayArgs) -> Self {
+        if !args.animate {
+            return Self::off();
+        }
+        let animator = Self::terminal(args.fps.unwrap_or(DEFAULT_FPS));
+        match args.viewport {
+            Some(viewport) => animator.with_viewport(viewport),
+            None => animator,
+        }
+    }
+
+    pub fn is_on(&self) -> bool {
+        !matches!(self.output, Output::Off)
+    }
+
+    /// The frames kept by a `capture` animator.
+    pub fn frames(&self) -> &[String] {
+        match &self.output {
+            Output::Capture(frames) => frames,
+            _ => &[],
+        }
+    }
+
+    fn viewport(&self, scene: &impl Scene) -> Viewport {
+        self.viewport.unwrap_or_else(|| {
+            let bounds = scene.bounds();
+            Viewport::new(
+                bounds.origin,
+                bounds.width.min(MAX_WIDTH),
+                bounds.height.min(MAX_HEIGHT),
+            )
+        })
+    }
+
+    /// Draws the current state of `scene` as the next frame.
+    pub fn show(&mut self, scene: &impl Scene) {
+        match self.output {
+            Output::Off => {}
+            Output::Capture(_) => {
+                let frame = scene.frame(&self.viewport(scene));
+                if let Output::Capture(frames) = &mut self.output {
+                    frames.push(frame);
+                }
+            }
+            Output::Terminal => {
+                let frame = scene.frame(&self.viewport(scene));
+                self.wait();
+                let mut stdout = io::stdout().lock();
+                // Moves the cursor to the top left and clears the screen before drawing the frame.
+                let _ = writeln!(stdout, "\x1b[H\x1b[J{}", frame);
+                let _ = stdout.flush();
+            }
+        }
+    }
+
+    /// Sleeps until the next frame is due.
+    fn wait(&mut self) {
+        if let (Some(last_frame), Ok(interval)) = (
+            self.last_frame,
+            Duration::try_from_secs_f64(1_f64 / self.fps),
+        ) {
+            if let Some(remaining) = interval.checked_sub(last_frame.elapsed()) {
+                thread::sleep(remaining);
+            }
+        }
+        self.last_frame = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_viewport() {
+        assert_eq!(
+            "490,-2,20,12".parse(),
+            Ok(Viewport::new(Point::new(490, -2), 20, 12))
+        );
+        assert!("490,0,20".parse::<Viewport>().is_err());
+        assert!("a,0,20,12".parse::<Viewport>().is_err());
+
+        let viewport = Viewport::around([Point::new(3, 1), Point::new(-1, 4)]).unwrap();
+        assert_eq!(viewport, Viewport::new(Point::new(-1, 1), 5, 4));
+        assert!(viewport.contains(Point::new(3, 4)));
+        assert!(!viewport.contains(Point::new(4, 4)));
+        assert_eq!(Viewport::around([]), None);
+    }
+
+    #[test]
+    fn test_frame() {
+        let grid = Grid::parse("#..\n.#.\n..#\n", Some).unwrap();
+        assert_eq!(grid.frame(&grid.bounds()), "#..\n.#.\n..#");
+        assert_eq!(
+            grid.frame(&Viewport::new(Point::new(1, 1), 3, 2)),
+            "#. \n.# "
+        );
+    }
+
+    #[test]
+    fn test_capture() {
+        let mut grid = Grid::new(2, 2, '.');
+        let mut animator = Animator::capture();
+        assert!(animator.is_on());
+        animator.show(&grid);
+        grid[Point::new(1, 0)] = '#';
+        animator.show(&grid);
+        assert_eq!(animator.frames(), ["..\n..", ".#\n.."]);
+
+        let mut animator = Animator::capture().with_viewport(Viewport::new(Point::new(1, 0), 1, 1));
+        animator.show(&grid);
+        assert_eq!(animator.frames(), ["#"]);
+
+        let mut animator = Animator::off();
+        animator.show(&grid);
+        assert!(!animator.is_on());
+        assert!(animator.frames().is_empty());
+    }
+}